@@ -0,0 +1,22 @@
+mod common;
+
+use {
+    common::EXAMPLE2,
+    criterion::{black_box, criterion_group, criterion_main, Criterion},
+    safe_bytes::SafeBytes,
+};
+
+const LEN: usize = 4096;
+
+fn array(c: &mut Criterion) {
+    let mut array = Box::new([EXAMPLE2; LEN]);
+    c.bench_function("array", |b| b.iter(|| black_box(array.safe_bytes().len())));
+
+    let mut vec = vec![EXAMPLE2; LEN];
+    c.bench_function("slice", |b| {
+        b.iter(|| black_box(vec[..].safe_bytes().len()))
+    });
+}
+
+criterion_group!(benches, array);
+criterion_main!(benches);
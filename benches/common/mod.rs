@@ -0,0 +1,15 @@
+#![allow(dead_code)]
+
+use safe_bytes::SafeBytes;
+
+/// Same struct as in `examples/derive.rs`.
+#[derive(Clone, Copy, SafeBytes)]
+#[repr(C)]
+pub struct Example2 {
+    pub a: u8,
+    pub b: u64,
+    pub c: u16,
+}
+
+/// Value used to fill benchmarked arrays.
+pub const EXAMPLE2: Example2 = Example2 { a: 1, b: 2, c: 3 };
@@ -0,0 +1,87 @@
+mod common;
+
+use {
+    common::{Example2, EXAMPLE2},
+    criterion::{black_box, criterion_group, criterion_main, Criterion},
+    safe_bytes::SafeBytes,
+};
+
+/// Same as `Example2` but without inlining hints.
+#[derive(Clone, Copy, SafeBytes)]
+#[safe_bytes(no_inline)]
+#[repr(C)]
+struct NoInline {
+    a: u8,
+    b: u64,
+    c: u16,
+}
+
+/// Struct with large padding region.
+#[derive(Clone, Copy, SafeBytes)]
+#[repr(C)]
+struct LargeGap {
+    a: u8,
+    b: Aligned,
+}
+
+#[derive(Clone, Copy, SafeBytes)]
+#[repr(C, align(256))]
+struct Aligned {
+    value: u64,
+}
+
+/// Struct with small array of padded structs, filled without a loop.
+#[derive(Clone, Copy, SafeBytes)]
+#[repr(C)]
+struct Unrolled {
+    tag: u16,
+    inner: [Example2; 4],
+}
+
+/// Same as `Unrolled` but array padding is filled in a loop.
+#[derive(Clone, Copy, SafeBytes)]
+#[safe_bytes(unroll = 0)]
+#[repr(C)]
+struct Looped {
+    tag: u16,
+    inner: [Example2; 4],
+}
+
+fn padding(c: &mut Criterion) {
+    let mut array = Box::new([EXAMPLE2; 1000]);
+    c.bench_function("example2_1000", |b| {
+        b.iter(|| black_box(black_box(&mut *array).safe_bytes().len()))
+    });
+
+    let mut array = Box::new([NoInline { a: 1, b: 2, c: 3 }; 1000]);
+    c.bench_function("no_inline_1000", |b| {
+        b.iter(|| black_box(black_box(&mut *array).safe_bytes().len()))
+    });
+
+    let mut large = Box::new(
+        [LargeGap {
+            a: 1,
+            b: Aligned { value: 2 },
+        }; 64],
+    );
+    c.bench_function("large_gap_64", |b| {
+        b.iter(|| black_box(black_box(&mut *large).safe_bytes().len()))
+    });
+}
+
+fn arrays(c: &mut Criterion) {
+    let inner = [EXAMPLE2; 4];
+
+    let mut array = Box::new([Unrolled { tag: 1, inner }; 250]);
+    c.bench_function("unrolled_250", |b| {
+        b.iter(|| black_box(black_box(&mut *array).safe_bytes().len()))
+    });
+
+    let mut array = Box::new([Looped { tag: 1, inner }; 250]);
+    c.bench_function("looped_250", |b| {
+        b.iter(|| black_box(black_box(&mut *array).safe_bytes().len()))
+    });
+}
+
+criterion_group!(benches, padding, arrays);
+criterion_main!(benches);
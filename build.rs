@@ -0,0 +1,42 @@
+use std::{env, process::Command};
+
+// `Option::is_some_and` is stable since 1.70,
+// and the script must run on compilers older than any probed feature.
+#[allow(clippy::unnecessary_map_or)]
+fn main() {
+    println!("cargo:rerun-if-changed=build.rs");
+    println!("cargo:rustc-check-cfg=cfg(safe_bytes_saturating)");
+    println!("cargo:rustc-check-cfg=cfg(safe_bytes_offset_of)");
+    println!("cargo:rustc-check-cfg=cfg(safe_bytes_core_error)");
+    println!("cargo:rustc-check-cfg=cfg(safe_bytes_const_mut)");
+
+    let minor = rustc_minor_version();
+
+    // `core::num::Saturating` is stable since 1.74.
+    if minor.map_or(false, |minor| minor >= 74) {
+        println!("cargo:rustc-cfg=safe_bytes_saturating");
+    }
+
+    // `core::mem::offset_of!` is stable since 1.77.
+    if minor.map_or(false, |minor| minor >= 77) {
+        println!("cargo:rustc-cfg=safe_bytes_offset_of");
+    }
+
+    // `core::error::Error` is stable since 1.81.
+    if minor.map_or(false, |minor| minor >= 81) {
+        println!("cargo:rustc-cfg=safe_bytes_core_error");
+    }
+
+    // Mutable references in `const fn` are stable since 1.83.
+    if minor.map_or(false, |minor| minor >= 83) {
+        println!("cargo:rustc-cfg=safe_bytes_const_mut");
+    }
+}
+
+fn rustc_minor_version() -> Option<u32> {
+    let rustc = env::var_os("RUSTC")?;
+    let output = Command::new(rustc).arg("--version").output().ok()?;
+    let version = String::from_utf8(output.stdout).ok()?;
+    let mut parts = version.strip_prefix("rustc 1.")?.split('.');
+    parts.next()?.parse().ok()
+}
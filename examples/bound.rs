@@ -0,0 +1,95 @@
+use {
+    core::{
+        mem::{offset_of, size_of, MaybeUninit},
+        ops::Bound,
+        ptr::write_bytes,
+    },
+    safe_bytes::{PaddingBane, SafeBytes},
+};
+
+/// `Bound<u32>` is a foreign type with unspecified layout,
+/// so it can't implement `PaddingBane`, even in a newtype.
+/// Mirror it with enum of primitive representation instead.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[repr(C, u8)]
+pub enum Bound32 {
+    Included(u32),
+    Excluded(u32),
+    Unbounded,
+}
+
+impl From<Bound<u32>> for Bound32 {
+    fn from(bound: Bound<u32>) -> Self {
+        match bound {
+            Bound::Included(value) => Bound32::Included(value),
+            Bound::Excluded(value) => Bound32::Excluded(value),
+            Bound::Unbounded => Bound32::Unbounded,
+        }
+    }
+}
+
+impl From<Bound32> for Bound<u32> {
+    fn from(bound: Bound32) -> Self {
+        match bound {
+            Bound32::Included(value) => Bound::Included(value),
+            Bound32::Excluded(value) => Bound::Excluded(value),
+            Bound32::Unbounded => Bound::Unbounded,
+        }
+    }
+}
+
+// `#[repr(C, u8)]` enum is laid out as this struct,
+// where payload is union of variants' fields.
+#[repr(C)]
+#[allow(dead_code)]
+struct Bound32Repr {
+    tag: u8,
+    payload: u32,
+}
+
+const _: () = assert!(size_of::<Bound32>() == size_of::<Bound32Repr>());
+
+const UNBOUNDED: u8 = 2;
+
+unsafe impl PaddingBane for Bound32 {
+    // Layout doesn't depend on the instance.
+    type Fields = ();
+
+    fn get_fields(&self) {}
+
+    unsafe fn init_padding(_fields: (), bytes: &mut [MaybeUninit<u8>]) {
+        // Tag is never a padding.
+        let tag = bytes[0].assume_init();
+        let payload = offset_of!(Bound32Repr, payload);
+
+        // Padding between tag and payload.
+        write_bytes(bytes[1..payload].as_mut_ptr(), 0xfe, payload - 1);
+
+        // `Unbounded` variant has no payload.
+        if tag == UNBOUNDED {
+            let count = bytes.len() - payload;
+            write_bytes(bytes[payload..].as_mut_ptr(), 0xfe, count);
+        }
+    }
+}
+
+fn main() {
+    let pad = [0xfe; 3];
+
+    let mut included = Bound32::from(Bound::Included(7));
+    let expected = [&[0][..], &pad, &7u32.to_ne_bytes()].concat();
+    assert_eq!(included.safe_bytes(), &expected[..]);
+
+    let mut excluded = Bound32::from(Bound::Excluded(9));
+    let expected = [&[1][..], &pad, &9u32.to_ne_bytes()].concat();
+    assert_eq!(excluded.safe_bytes(), &expected[..]);
+
+    let mut unbounded = Bound32::from(Bound::Unbounded);
+    let expected = [&[UNBOUNDED][..], &pad, &[0xfe; 4]].concat();
+    assert_eq!(unbounded.safe_bytes(), &expected[..]);
+
+    // Bytes are stable across calls and conversions.
+    let bytes = included.safe_bytes().to_vec();
+    let mut roundtrip = Bound32::from(Bound::from(included));
+    assert_eq!(roundtrip.safe_bytes(), &bytes[..]);
+}
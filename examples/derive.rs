@@ -44,20 +44,20 @@ unsafe impl PaddingBane for Example {
         } = fields;
 
         // First find offsets and sizes of all fields.
-        let mut fields = [a_field.raw, b_field.raw, c_field.raw];
+        let mut fields = [a_field.raw(), b_field.raw(), c_field.raw()];
 
         // Sort fields by offset.
-        fields.sort_unstable_by_key(|f| f.offset);
+        fields.sort_unstable_by_key(|f| f.offset());
 
         // Find any padding between fields and fill it.
         let mut offset = 0;
         for field in &fields {
-            if field.offset > offset {
-                let count = field.offset - offset;
+            if field.offset() > offset {
+                let count = field.offset() - offset;
                 // Fill padding.
                 write_bytes(&mut bytes[offset], 0xfe, count);
             }
-            offset = field.offset + field.size;
+            offset = field.end();
         }
 
         // Padding at the end
@@ -67,14 +67,14 @@ unsafe impl PaddingBane for Example {
         }
 
         // Repeat recursively for each field.
-        let a_bytes = &mut bytes[a_field.raw.offset..a_field.raw.offset + a_field.raw.size];
-        <u8 as PaddingBane>::init_padding(a_field.sub, a_bytes);
+        let a_bytes = &mut bytes[a_field.raw().offset()..a_field.raw().end()];
+        <u8 as PaddingBane>::init_padding(a_field.sub(), a_bytes);
 
-        let b_bytes = &mut bytes[b_field.raw.offset..b_field.raw.offset + b_field.raw.size];
-        <u64 as PaddingBane>::init_padding(b_field.sub, b_bytes);
+        let b_bytes = &mut bytes[b_field.raw().offset()..b_field.raw().end()];
+        <u64 as PaddingBane>::init_padding(b_field.sub(), b_bytes);
 
-        let c_bytes = &mut bytes[c_field.raw.offset..c_field.raw.offset + a_field.raw.size];
-        <u16 as PaddingBane>::init_padding(c_field.sub, c_bytes);
+        let c_bytes = &mut bytes[c_field.raw().offset()..c_field.raw().end()];
+        <u16 as PaddingBane>::init_padding(c_field.sub(), c_bytes);
     }
 }
 
@@ -91,7 +91,7 @@ const SAFE_BYTES: [u8; 24] = [
     0x01, // a
     0xfe, 0xfe, 0xfe, 0xfe, 0xfe, 0xfe, 0xfe, // pad
     0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02, // b,
-    0x00, 0x00, // c,
+    0x00, 0x03, // c,
     0xfe, 0xfe, 0xfe, 0xfe, 0xfe, 0xfe, // pad
 ];
 
@@ -112,4 +112,5 @@ fn main() {
     let mut example = Example2 { a: 1, b: 2, c: 3 };
     let bytes = example.safe_bytes();
     assert_eq!(bytes, &SAFE_BYTES);
+    assert!(example.safe_bytes_matches(&SAFE_BYTES));
 }
@@ -0,0 +1,137 @@
+use {
+    core::{
+        mem::{offset_of, size_of, MaybeUninit},
+        ptr::write_bytes,
+    },
+    safe_bytes::{PaddingBane, SafeBytes},
+};
+
+/// Only fieldless enums can derive `SafeBytes`, but enums with primitive representation
+/// have defined layout and may implement `PaddingBane` manually.
+#[repr(C, u8)]
+pub enum Shape {
+    Circle { radius: f32 },
+    Rect { width: u16, height: u64 },
+    Empty,
+}
+
+// `#[repr(C, u8)]` enum is laid out as `#[repr(C)]` struct with `u8` tag
+// followed by `#[repr(C)]` union of `#[repr(C)]` structs, one for each variant.
+// Tags are assigned from 0 in declaration order.
+// Those structs derive `SafeBytes` to fill padding inside variants.
+
+#[derive(Clone, Copy, SafeBytes)]
+#[repr(C)]
+struct CircleRepr {
+    radius: f32,
+}
+
+#[derive(Clone, Copy, SafeBytes)]
+#[repr(C)]
+struct RectRepr {
+    width: u16,
+    height: u64,
+}
+
+#[repr(C)]
+#[allow(dead_code)]
+union ShapePayload {
+    circle: CircleRepr,
+    rect: RectRepr,
+    empty: (),
+}
+
+#[repr(C)]
+#[allow(dead_code)]
+struct ShapeRepr {
+    tag: u8,
+    payload: ShapePayload,
+}
+
+const _: () = assert!(size_of::<Shape>() == size_of::<ShapeRepr>());
+
+#[derive(Clone, Copy)]
+pub struct ShapeFields {
+    payload_offset: usize,
+    circle: <CircleRepr as PaddingBane>::Fields,
+    rect: <RectRepr as PaddingBane>::Fields,
+}
+
+unsafe impl PaddingBane for Shape {
+    type Fields = ShapeFields;
+
+    fn get_fields(&self) -> ShapeFields {
+        // Fields must not depend on the active variant.
+        // Any instance of variant structs would do.
+        ShapeFields {
+            payload_offset: offset_of!(ShapeRepr, payload),
+            circle: CircleRepr { radius: 0.0 }.get_fields(),
+            rect: RectRepr {
+                width: 0,
+                height: 0,
+            }
+            .get_fields(),
+        }
+    }
+
+    unsafe fn init_padding(fields: ShapeFields, bytes: &mut [MaybeUninit<u8>]) {
+        // Tag is never a padding.
+        let tag = bytes[0].assume_init();
+
+        // Padding between tag and payload.
+        let count = fields.payload_offset - 1;
+        write_bytes(bytes[1..].as_mut_ptr(), 0xfe, count);
+
+        // Fill padding of the active variant.
+        let payload = &mut bytes[fields.payload_offset..];
+        let used = match tag {
+            0 => {
+                let size = size_of::<CircleRepr>();
+                CircleRepr::init_padding(fields.circle, &mut payload[..size]);
+                size
+            }
+            1 => {
+                let size = size_of::<RectRepr>();
+                RectRepr::init_padding(fields.rect, &mut payload[..size]);
+                size
+            }
+            _ => 0,
+        };
+
+        // Bytes not used by the active variant are padding as well.
+        let count = payload.len() - used;
+        write_bytes(payload[used..].as_mut_ptr(), 0xfe, count);
+    }
+}
+
+fn main() {
+    let pad = |count| vec![0xfe; count];
+
+    let mut circle = Shape::Circle { radius: 1.5 };
+    let expected = [
+        vec![0],
+        pad(7),
+        1.5f32.to_ne_bytes().to_vec(), // radius
+        pad(12),
+    ]
+    .concat();
+    assert_eq!(circle.safe_bytes(), &expected[..]);
+
+    let mut rect = Shape::Rect {
+        width: 3,
+        height: 4,
+    };
+    let expected = [
+        vec![1],
+        pad(7),
+        3u16.to_ne_bytes().to_vec(), // width
+        pad(6),
+        4u64.to_ne_bytes().to_vec(), // height
+    ]
+    .concat();
+    assert_eq!(rect.safe_bytes(), &expected[..]);
+
+    let mut empty = Shape::Empty;
+    let expected = [vec![2], pad(23)].concat();
+    assert_eq!(empty.safe_bytes(), &expected[..]);
+}
@@ -0,0 +1,36 @@
+use safe_bytes::{impl_padding_bane_struct, SafeBytes};
+
+/// Imitates module generated by `bindgen`
+/// or otherwise out of user's control.
+mod external {
+    #[repr(C)]
+    pub struct Header {
+        pub kind: u8,
+        pub len: u32,
+        pub flags: u16,
+    }
+}
+
+impl_padding_bane_struct!(external::Header {
+    kind: u8,
+    len: u32,
+    flags: u16,
+});
+
+fn main() {
+    let mut header = external::Header {
+        kind: 1,
+        len: 2,
+        flags: 3,
+    };
+
+    let expected = [
+        vec![1],                     // kind
+        vec![0xfe; 3],               // pad
+        2u32.to_ne_bytes().to_vec(), // len
+        3u16.to_ne_bytes().to_vec(), // flags
+        vec![0xfe; 2],               // pad
+    ]
+    .concat();
+    assert_eq!(header.safe_bytes(), &expected[..]);
+}
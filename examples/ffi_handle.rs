@@ -0,0 +1,47 @@
+use {
+    core::{ffi::c_void, mem::size_of, ptr::null_mut},
+    safe_bytes::SafeBytes,
+};
+
+/// Opaque handle returned by a C library.
+/// Pointer is plain address, so the newtype derives `SafeBytes` as any other struct.
+#[derive(SafeBytes)]
+#[repr(transparent)]
+struct Handle(*mut c_void);
+
+/// Request passed to the library along with the handle.
+#[derive(SafeBytes)]
+#[repr(C)]
+struct Request {
+    kind: u8,
+    handle: Handle,
+    len: u32,
+}
+
+fn main() {
+    let mut resource = 0u64;
+    let address = &mut resource as *mut u64 as usize;
+
+    let mut request = Request {
+        kind: 1,
+        handle: Handle(&mut resource as *mut u64 as *mut c_void),
+        len: 16,
+    };
+
+    // Padding after `kind` and after `len` is filled,
+    // pointer bytes are exposed as is.
+    let ptr = size_of::<usize>();
+    let expected = [
+        &[1][..],
+        &vec![0xfe; ptr - 1],
+        &address.to_ne_bytes(),
+        &16u32.to_ne_bytes(),
+        &vec![0xfe; ptr - 4],
+    ]
+    .concat();
+    assert_eq!(request.safe_bytes(), &expected[..]);
+
+    // Closed handle is null.
+    request.handle = Handle(null_mut());
+    assert_eq!(request.safe_bytes()[ptr..2 * ptr], 0usize.to_ne_bytes());
+}
@@ -0,0 +1,63 @@
+use {
+    core::{
+        mem::{offset_of, size_of},
+        ptr::null_mut,
+        sync::atomic::{AtomicPtr, AtomicU32, AtomicU8, Ordering},
+    },
+    safe_bytes::SafeBytes,
+};
+
+/// Control block of a lock-free queue placed into shared memory.
+/// Differently aligned atomics leave padding between them.
+#[derive(SafeBytes)]
+#[repr(C)]
+struct ControlBlock {
+    ready: AtomicU8,
+    head: AtomicU32,
+    tail: AtomicU32,
+    slots: [AtomicPtr<u64>; 2],
+    closed: AtomicU8,
+}
+
+fn main() {
+    let mut value = 42u64;
+    let mut block = ControlBlock {
+        ready: AtomicU8::new(0),
+        head: AtomicU32::new(0),
+        tail: AtomicU32::new(0),
+        slots: [AtomicPtr::new(null_mut()), AtomicPtr::new(null_mut())],
+        closed: AtomicU8::new(0),
+    };
+
+    // Producer updates the block through shared references.
+    block.slots[0].store(&mut value, Ordering::Release);
+    block.tail.fetch_add(1, Ordering::AcqRel);
+    block.ready.store(1, Ordering::Release);
+
+    // Region shared with another process.
+    // Note that pointers are meaningful only within the address space
+    // they were created in, the other side should treat them as opaque tokens.
+    let mut region = [0u8; size_of::<ControlBlock>()];
+    region.copy_from_slice(block.safe_bytes());
+
+    let pad = 0xfe;
+    let ready = offset_of!(ControlBlock, ready);
+    let head = offset_of!(ControlBlock, head);
+    let tail = offset_of!(ControlBlock, tail);
+    let slots = offset_of!(ControlBlock, slots);
+    let closed = offset_of!(ControlBlock, closed);
+
+    assert_eq!(region[ready], 1);
+    assert!(region[ready + 1..head].iter().all(|&b| b == pad));
+    assert_eq!(region[head..head + 4], 0u32.to_ne_bytes());
+    assert_eq!(region[tail..tail + 4], 1u32.to_ne_bytes());
+    assert!(region[tail + 4..slots].iter().all(|&b| b == pad));
+
+    let slot = size_of::<AtomicPtr<u64>>();
+    let first = (&mut value as *mut u64 as usize).to_ne_bytes();
+    assert_eq!(region[slots..slots + slot], first[..]);
+    assert_eq!(region[slots + slot..closed], 0usize.to_ne_bytes()[..]);
+
+    assert_eq!(region[closed], 0);
+    assert!(region[closed + 1..].iter().all(|&b| b == pad));
+}
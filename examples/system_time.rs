@@ -0,0 +1,41 @@
+use {
+    safe_bytes::SafeBytes,
+    std::time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+/// `SystemTime` can't implement `SafeBytes`.
+/// Time since UNIX epoch is serialized instead.
+#[derive(SafeBytes)]
+#[repr(C)]
+struct Timestamp {
+    secs: u64,
+    nanos: u32,
+}
+
+impl Timestamp {
+    fn from_system_time(time: SystemTime) -> Self {
+        let since_epoch = time
+            .duration_since(UNIX_EPOCH)
+            .expect("time before UNIX epoch");
+        Timestamp {
+            secs: since_epoch.as_secs(),
+            nanos: since_epoch.subsec_nanos(),
+        }
+    }
+
+    fn to_system_time(&self) -> SystemTime {
+        UNIX_EPOCH + Duration::new(self.secs, self.nanos)
+    }
+}
+
+fn main() {
+    let now = SystemTime::now();
+    let mut timestamp = Timestamp::from_system_time(now);
+    assert_eq!(timestamp.to_system_time(), now);
+
+    let (secs, nanos) = (timestamp.secs, timestamp.nanos);
+    let bytes = timestamp.safe_bytes();
+    assert_eq!(bytes[..8], secs.to_ne_bytes());
+    assert_eq!(bytes[8..12], nanos.to_ne_bytes());
+    assert_eq!(bytes[12..], [0xfe; 4]);
+}
@@ -0,0 +1,60 @@
+#![no_main]
+
+use {
+    core::mem::MaybeUninit,
+    libfuzzer_sys::fuzz_target,
+    safe_bytes::{fill_padding, Field, SafeBytesError},
+};
+
+/// Byte written into field bytes before filling the padding.
+const FIELD_BYTE: u8 = 0xaa;
+
+// First byte is the size of the value,
+// each following pair of bytes is offset and size of a field.
+// Fields may go out of bounds and overlap.
+fuzz_target!(|data: &[u8]| {
+    let (&len, data) = match data.split_first() {
+        Some(split) => split,
+        None => return,
+    };
+    let len = len as usize;
+
+    let mut fields = data
+        .chunks_exact(2)
+        .map(|pair| Field::new(pair[0] as usize, pair[1] as usize))
+        .collect::<Vec<_>>();
+
+    // Field may end at most at `255 + 255`.
+    let mut covered = vec![0usize; 512];
+    for field in &fields {
+        for byte in &mut covered[field.offset()..field.end()] {
+            *byte += 1;
+        }
+    }
+    let out_of_bounds = fields.iter().any(|f| f.end() > len);
+    let overlap = covered.iter().any(|&c| c > 1);
+
+    let mut bytes = vec![MaybeUninit::new(FIELD_BYTE); len];
+    match fill_padding(&mut fields, &mut bytes) {
+        Ok(count) => {
+            assert!(!out_of_bounds && !overlap);
+
+            // Every byte is either field byte, left intact, or padding.
+            let bytes = bytes
+                .iter()
+                .map(|b| unsafe { b.assume_init() })
+                .collect::<Vec<_>>();
+            for (byte, &covered) in bytes.iter().zip(&covered) {
+                let expected = if covered > 0 { FIELD_BYTE } else { 0xfe };
+                assert_eq!(*byte, expected);
+            }
+            assert_eq!(count, covered[..len].iter().filter(|&&c| c == 0).count());
+        }
+        Err(SafeBytesError::FieldOutOfBounds { index }) => {
+            assert!(out_of_bounds);
+            assert!(fields[index].end() > len);
+        }
+        Err(SafeBytesError::FieldOverlap) => assert!(!out_of_bounds && overlap),
+        Err(err) => panic!("unexpected error: {}", err),
+    }
+});
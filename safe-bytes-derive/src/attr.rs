@@ -0,0 +1,198 @@
+use {
+    proc_macro2::TokenStream,
+    quote::quote,
+    syn::{Attribute, Error, Lit, LitInt, Meta, NestedMeta, Path, Result},
+};
+
+/// Attributes applied to the type with `#[safe_bytes(...)]`.
+#[derive(Default)]
+pub struct ContainerAttrs {
+    /// Union's members cover all bytes and active member initializes them.
+    pub union_all_init: bool,
+
+    /// Omit inlining hints from generated impl.
+    pub no_inline: bool,
+
+    /// Fill gap after each field with marker encoding field's index.
+    pub pad_per_field: bool,
+
+    /// Generate method returning bytes without trailing padding.
+    pub no_tail_pad: bool,
+
+    /// Reject `usize` and `isize` in field types.
+    pub no_platform_ints: bool,
+
+    /// Generate method writing fields bytes in declaration order.
+    pub declaration_order: bool,
+
+    /// Implement `PortableBytes`.
+    pub portable: bool,
+
+    /// Generate methods writing bytes with numeric fields in given byte order.
+    pub byteorder: bool,
+
+    /// Generate constant listing name, offset and size of each field.
+    pub layout_const: bool,
+
+    /// Function that fills padding gaps after sentinel.
+    pub pad_with_fn: Option<Path>,
+
+    /// Expected size of the type.
+    pub assert_size: Option<LitInt>,
+
+    /// Longest array field which padding is filled element by element
+    /// without a loop.
+    pub unroll: Option<usize>,
+}
+
+impl ContainerAttrs {
+    pub fn parse(attrs: &[Attribute]) -> Result<Self> {
+        let mut result = ContainerAttrs::default();
+
+        for meta in safe_bytes_metas(attrs)? {
+            match &meta {
+                NestedMeta::Meta(Meta::Path(path)) if path.is_ident("union_all_init") => {
+                    result.union_all_init = true;
+                }
+                NestedMeta::Meta(Meta::Path(path)) if path.is_ident("no_inline") => {
+                    result.no_inline = true;
+                }
+                NestedMeta::Meta(Meta::Path(path)) if path.is_ident("pad_per_field") => {
+                    result.pad_per_field = true;
+                }
+                NestedMeta::Meta(Meta::Path(path)) if path.is_ident("no_tail_pad") => {
+                    result.no_tail_pad = true;
+                }
+                NestedMeta::Meta(Meta::Path(path)) if path.is_ident("no_platform_ints") => {
+                    result.no_platform_ints = true;
+                }
+                NestedMeta::Meta(Meta::Path(path)) if path.is_ident("declaration_order") => {
+                    result.declaration_order = true;
+                }
+                NestedMeta::Meta(Meta::Path(path)) if path.is_ident("portable") => {
+                    result.portable = true;
+                }
+                NestedMeta::Meta(Meta::Path(path)) if path.is_ident("byteorder") => {
+                    result.byteorder = true;
+                }
+                NestedMeta::Meta(Meta::Path(path)) if path.is_ident("layout_const") => {
+                    result.layout_const = true;
+                }
+                NestedMeta::Meta(Meta::NameValue(name_value))
+                    if name_value.path.is_ident("assert_size") =>
+                {
+                    match &name_value.lit {
+                        Lit::Int(size) => result.assert_size = Some(size.clone()),
+                        lit => return Err(Error::new_spanned(lit, "expected integer literal")),
+                    }
+                }
+                NestedMeta::Meta(Meta::NameValue(name_value))
+                    if name_value.path.is_ident("pad_with_fn") =>
+                {
+                    match &name_value.lit {
+                        Lit::Str(path) => result.pad_with_fn = Some(path.parse()?),
+                        lit => return Err(Error::new_spanned(lit, "expected string literal")),
+                    }
+                }
+                NestedMeta::Meta(Meta::NameValue(name_value))
+                    if name_value.path.is_ident("unroll") =>
+                {
+                    match &name_value.lit {
+                        Lit::Int(len) => result.unroll = Some(len.base10_parse()?),
+                        lit => return Err(Error::new_spanned(lit, "expected integer literal")),
+                    }
+                }
+                _ => return Err(Error::new_spanned(meta, "unknown `safe_bytes` attribute")),
+            }
+        }
+
+        if let (true, Some(path)) = (result.pad_per_field, &result.pad_with_fn) {
+            return Err(Error::new_spanned(
+                path,
+                "`pad_with_fn` cannot be combined with `pad_per_field`",
+            ));
+        }
+
+        Ok(result)
+    }
+
+    /// Returns longest array field length to unroll.
+    pub fn unroll(&self) -> usize {
+        self.unroll.unwrap_or(0)
+    }
+
+    /// Returns `#[inline]` attribute unless disabled.
+    pub fn inline(&self) -> TokenStream {
+        if self.no_inline {
+            TokenStream::new()
+        } else {
+            quote!(#[inline])
+        }
+    }
+
+    /// Returns `#[inline(always)]` attribute unless disabled.
+    pub fn inline_always(&self) -> TokenStream {
+        if self.no_inline {
+            TokenStream::new()
+        } else {
+            quote!(#[inline(always)])
+        }
+    }
+}
+
+/// Attributes applied to the field with `#[safe_bytes(...)]`.
+#[derive(Default)]
+pub struct FieldAttrs {
+    /// Fill whole field with sentinel instead of descending into it.
+    pub opaque: bool,
+
+    /// Assert at compile time that field's type has no padding.
+    pub deny_padding: bool,
+}
+
+impl FieldAttrs {
+    pub fn parse(attrs: &[Attribute]) -> Result<Self> {
+        let mut result = FieldAttrs::default();
+        let mut recurse = None;
+
+        for meta in safe_bytes_metas(attrs)? {
+            match &meta {
+                NestedMeta::Meta(Meta::Path(path)) if path.is_ident("recurse") => {
+                    recurse = Some(path.clone());
+                }
+                NestedMeta::Meta(Meta::Path(path)) if path.is_ident("opaque") => {
+                    result.opaque = true;
+                }
+                NestedMeta::Meta(Meta::Path(path)) if path.is_ident("deny_padding") => {
+                    result.deny_padding = true;
+                }
+                _ => {
+                    return Err(Error::new_spanned(
+                        meta,
+                        "unknown `safe_bytes` field attribute",
+                    ))
+                }
+            }
+        }
+
+        match recurse {
+            Some(path) if result.opaque => Err(Error::new_spanned(
+                path,
+                "`recurse` cannot be combined with `opaque`",
+            )),
+            _ => Ok(result),
+        }
+    }
+}
+
+/// Collects items from all `#[safe_bytes(...)]` attributes.
+fn safe_bytes_metas(attrs: &[Attribute]) -> Result<Vec<NestedMeta>> {
+    let mut metas = Vec::new();
+    for attr in attrs.iter().filter(|attr| attr.path.is_ident("safe_bytes")) {
+        match attr.parse_meta()? {
+            Meta::List(list) => metas.extend(list.nested),
+            meta => return Err(Error::new_spanned(meta, "expected `#[safe_bytes(...)]`")),
+        }
+    }
+    Ok(metas)
+}
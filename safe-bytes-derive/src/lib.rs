@@ -1,73 +1,1083 @@
-use {proc_macro2::TokenStream, quote::quote, syn::spanned::Spanned as _};
+mod attr;
+
+use {
+    crate::attr::{ContainerAttrs, FieldAttrs},
+    proc_macro2::TokenStream,
+    quote::{format_ident, quote, quote_spanned},
+    syn::spanned::Spanned as _,
+};
 
 /// Safely implements [`SafeBytes`] via [`PaddingBane`] implementation.
 ///
+/// Generated methods are marked with inlining hints.
+/// `#[safe_bytes(no_inline)]` attribute omits them,
+/// leaving codegen decisions to the compiler.
+///
+/// `#[safe_bytes(pad_per_field)]` attribute makes padding after each field
+/// filled with `0xf0 | index` byte, where `index` is the index of the field
+/// in declaration order, instead of `0xfe`.
+/// This makes hex dumps show which gap is which.
+/// Fields with index 14 and above and the gap before the first field use `0xfe`,
+/// as `0xf0 | 14` is the sentinel itself.
+///
+/// `#[safe_bytes(pad_with_fn = "path")]` attribute calls function
+/// `fn(&mut [u8])` on each padding gap, e.g. to write canaries.
+/// Gaps are filled with `0xfe` before the call,
+/// so bytes left untouched by the function keep that value.
+/// Fields' bytes and padding inside fields are not passed to the function.
+/// Can't be combined with `pad_per_field`.
+///
+/// Padding inside fields is filled recursively, keeping fields' data intact.
+/// `#[safe_bytes(recurse)]` field attribute states this default explicitly.
+/// `#[safe_bytes(opaque)]` field attribute fills whole field with `0xfe` instead,
+/// overwriting its value in place.
+/// Type of such field must implement [`FromSafeBytes`],
+/// so that sentinel bytes are its valid value.
+///
+/// `#[safe_bytes(deny_padding)]` field attribute checks at compile time
+/// that type of the field has no padding, including padding of nested fields.
+/// Useful for fields that are transmuted or compared bytewise elsewhere.
+///
+/// `#[safe_bytes(no_tail_pad)]` attribute generates inherent
+/// `safe_bytes_no_tail` method and `NO_TAIL_SIZE` constant.
+/// The method skips filling trailing padding and returns bytes
+/// up to the end of the last field, i.e. `NO_TAIL_SIZE` bytes,
+/// for values embedded into buffers that handle the tail separately.
+/// [`PaddingBane`] implementation still fills trailing padding,
+/// as arrays and enclosing structs rely on it.
+/// Can't be combined with `pad_per_field`.
+///
+/// `#[safe_bytes(no_platform_ints)]` attribute rejects `usize` and `isize`
+/// in field types, including array elements and generic arguments,
+/// for formats that must not depend on target's pointer width.
+/// Types hiding them behind aliases or in own fields are not detected.
+///
+/// `#[safe_bytes(declaration_order)]` attribute generates inherent
+/// `declaration_order_bytes` method and `DECLARATION_ORDER_SIZE` constant.
+/// The method copies bytes of the fields into provided buffer
+/// in declaration order without padding between them,
+/// regardless of how compiler laid out the fields.
+/// Fields themselves are copied in their own layout.
+///
+/// `#[safe_bytes(unroll = N)]` attribute makes padding of array fields
+/// with literal length up to `N` filled element by element without a loop.
+/// By default padding of all array fields is filled in a loop.
+///
+/// `#[safe_bytes(portable)]` attribute implements [`PortableBytes`]
+/// writing fields in declaration order in portable form.
+/// All field types must implement [`PortableBytes`].
+///
+/// `#[safe_bytes(byteorder)]` attribute generates inherent
+/// `to_le_safe_bytes` and `to_be_safe_bytes` methods.
+/// They copy bytes of the value into a buffer, keeping target's layout and padding,
+/// and swap bytes of integer and float fields, and arrays of them, into given byte order.
+/// Numeric fields are recognized by type name, e.g. `u32` or `core::primitive::u32`.
+/// Other fields, including nested structs, type aliases and generic parameters,
+/// must be at most one byte or arrays of such,
+/// otherwise compilation fails, as their byte order can't be told.
+///
+/// `#[safe_bytes(layout_const)]` attribute generates inherent `LAYOUT` constant
+/// listing `(name, offset, size)` of each field in declaration order.
+/// Fields of tuple structs are named by their indices.
+///
+/// There is no attribute to extend field's region past size of its type,
+/// e.g. for trailing flexible members.
+/// Bytes after the last field are trailing padding,
+/// and moving the struct leaves them uninitialized
+/// even if they were written before, so they are always filled.
+/// Declare such bytes as a field, e.g. an array, to keep them.
+///
+/// `#[safe_bytes(assert_size = N)]` attribute checks at compile time
+/// that size of the struct is `N` bytes.
+/// Useful to keep FFI structs in sync with C counterparts.
+///
+/// Fields disabled with `#[cfg(...)]` are removed before the derive runs,
+/// so generated code follows fields of the compiled struct.
+///
+/// Enums are supported only when fieldless and with `#[repr(C)]`
+/// or integer representation, e.g. `#[repr(u8)]`.
+/// Discriminant has no padding, so their bytes are returned as is.
+/// `align(N)` representation is rejected, as it adds padding after the discriminant.
+///
+/// Unions are supported only with `#[safe_bytes(union_all_init)]` attribute.
+/// All members of such union must implement [`PaddingBane`] without padding
+/// and have the same size as the union itself,
+/// so that the active member initializes all bytes.
+/// Both are checked at compile time.
+///
 /// [`SafeBytes`]: https://docs.rs/safe-bytes/0.1.0/safe_bytes/trait.SafeBytes.html
 /// [`PaddingBane`]: https://docs.rs/safe-bytes/0.1.0/safe_bytes/trait.PaddingBane.html
-#[proc_macro_derive(SafeBytes)]
+/// [`PortableBytes`]: https://docs.rs/safe-bytes/0.1.0/safe_bytes/trait.PortableBytes.html
+/// [`FromSafeBytes`]: https://docs.rs/safe-bytes/0.1.0/safe_bytes/trait.FromSafeBytes.html
+#[proc_macro_derive(SafeBytes, attributes(safe_bytes))]
 pub fn safe_bytes(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     let ast = syn::parse(input).unwrap();
-    impl_safe_bytes(&ast).into()
+    impl_safe_bytes(&ast)
+        .unwrap_or_else(|err| err.to_compile_error())
+        .into()
 }
 
-fn impl_safe_bytes(ast: &syn::DeriveInput) -> TokenStream {
+fn impl_safe_bytes(ast: &syn::DeriveInput) -> syn::Result<TokenStream> {
+    let attrs = ContainerAttrs::parse(&ast.attrs)?;
+
+    match &ast.data {
+        syn::Data::Struct(datastruct) if attrs.no_tail_pad && attrs.pad_per_field => {
+            Err(syn::Error::new(
+                datastruct.struct_token.span,
+                "`no_tail_pad` cannot be combined with `pad_per_field`",
+            ))
+        }
+        syn::Data::Struct(datastruct) => {
+            reject_references(&datastruct.fields)?;
+            if attrs.no_platform_ints {
+                reject_platform_ints(&datastruct.fields)?;
+            }
+            let field_attrs = datastruct
+                .fields
+                .iter()
+                .map(|f| FieldAttrs::parse(&f.attrs))
+                .collect::<syn::Result<Vec<_>>>()?;
+            Ok(impl_struct(ast, &attrs, &datastruct.fields, &field_attrs))
+        }
+        syn::Data::Union(dataunion) if attrs.portable => Err(syn::Error::new(
+            dataunion.union_token.span,
+            "`portable` attribute is not supported for unions",
+        )),
+        syn::Data::Union(dataunion) if attrs.no_tail_pad => Err(syn::Error::new(
+            dataunion.union_token.span,
+            "`no_tail_pad` attribute is not supported for unions",
+        )),
+        syn::Data::Union(dataunion) if attrs.byteorder => Err(syn::Error::new(
+            dataunion.union_token.span,
+            "`byteorder` attribute is not supported for unions",
+        )),
+        syn::Data::Union(dataunion) if attrs.layout_const => Err(syn::Error::new(
+            dataunion.union_token.span,
+            "`layout_const` attribute is not supported for unions",
+        )),
+        syn::Data::Union(_) if attrs.pad_with_fn.is_some() => Err(syn::Error::new_spanned(
+            &attrs.pad_with_fn,
+            "`pad_with_fn` attribute is not supported for unions",
+        )),
+        syn::Data::Union(dataunion) if attrs.union_all_init => {
+            if attrs.no_platform_ints {
+                reject_platform_ints(&syn::Fields::Named(dataunion.fields.clone()))?;
+            }
+            for f in &dataunion.fields.named {
+                let field_attrs = FieldAttrs::parse(&f.attrs)?;
+                if field_attrs.opaque {
+                    return Err(syn::Error::new_spanned(
+                        f,
+                        "`opaque` attribute is not supported for union fields",
+                    ));
+                }
+                if field_attrs.deny_padding {
+                    return Err(syn::Error::new_spanned(
+                        f,
+                        "`deny_padding` attribute is not supported for union fields",
+                    ));
+                }
+            }
+            Ok(impl_union(ast, &attrs, &dataunion.fields))
+        }
+        syn::Data::Union(dataunion) => Err(syn::Error::new(
+            dataunion.union_token.span,
+            "safe_bytes cannot be derived for unions without `#[safe_bytes(union_all_init)]`",
+        )),
+        syn::Data::Enum(dataenum) => impl_enum(ast, &attrs, dataenum),
+    }
+}
+
+fn impl_struct(
+    ast: &syn::DeriveInput,
+    attrs: &ContainerAttrs,
+    fields: &syn::Fields,
+    field_attrs: &[FieldAttrs],
+) -> TokenStream {
     let type_name = &ast.ident;
-    let fields = match &ast.data {
-        syn::Data::Struct(datastruct) => &datastruct.fields,
-        _ => panic!("safe_bytes cannot be derived for enums or unions"),
-    };
+    let inline = attrs.inline();
+    let inline_always = attrs.inline_always();
 
     let field_types = fields.iter().map(|f| f.ty.clone()).collect::<Vec<_>>();
-    let field_names = fields
+    // Locals holding fields metadata are not named after the fields,
+    // so that they don't shadow other locals of generated code, e.g. `padding`.
+    let field_names = (0..fields.len())
+        .map(|i| format_ident!("__safe_bytes_field_{}", i))
+        .collect::<Vec<_>>();
+
+    let field_members = fields
         .iter()
         .enumerate()
-        .map(|(i, f)| {
-            f.ident
-                .clone()
-                .unwrap_or_else(|| syn::Ident::new(&format!("_{}", i), ast.span()))
+        .map(|(i, f)| match &f.ident {
+            Some(ident) => syn::Member::Named(ident.clone()),
+            None => syn::Member::Unnamed(i.into()),
         })
         .collect::<Vec<_>>();
+    let field_indices = (0..fields.len()).map(proc_macro2::Literal::usize_unsuffixed);
+    let field_count = fields.len();
+    let gap_count = fields.len() + 1;
 
-    let (impl_generics, type_generics, where_clause) = ast.generics.split_for_impl();
+    let fill_gap = match &attrs.pad_with_fn {
+        Some(pad_with_fn) => quote! {
+            ::safe_bytes::__fill_with(&mut bytes[offset..offset + count], #pad_with_fn);
+        },
+        None => quote! {
+            ::safe_bytes::__fill(&mut bytes[offset..offset + count], 0xfe);
+        },
+    };
+
+    let gaps = if attrs.pad_per_field {
+        let markers = (0..fields.len()).map(|i| if i < 14 { 0xf0 | i as u8 } else { 0xfe });
+        quote! {
+            use ::safe_bytes::core::mem::size_of;
+
+            let mut raw_fields: [(u8, ::safe_bytes::Field); #field_count] = [#((#markers, #field_names.raw()),)*];
+            raw_fields.sort_unstable_by_key(|(_, f)| f.offset());
+            let mut padding = 0;
+            let mut offset = 0;
+            let mut marker = 0xfe;
+            for (field_marker, field) in &raw_fields {
+                if field.offset() > offset {
+                    let count = field.offset() - offset;
+                    ::safe_bytes::__fill(&mut bytes[offset..offset + count], marker);
+                    padding += count;
+                }
+                offset = ::safe_bytes::core::cmp::max(offset, field.end());
+                marker = *field_marker;
+            }
+
+            if size_of::<Self>() > offset {
+                let count = size_of::<Self>() - offset;
+                ::safe_bytes::__fill(&mut bytes[offset..offset + count], marker);
+                padding += count;
+            }
+        }
+    } else {
+        // Gaps are computed at compile time,
+        // so that filling arrays of the type doesn't sort fields for each element.
+        quote! {
+            let mut padding = 0;
+            for &(offset, count) in <Self as ::safe_bytes::PaddingBane>::PADDING_MAP {
+                #fill_gap
+                padding += count;
+            }
+        }
+    };
+
+    let field_fills = field_types
+        .iter()
+        .zip(&field_names)
+        .zip(field_attrs)
+        .map(|((ty, name), field_attrs)| match unrolled_array(ty, attrs.unroll()) {
+            _ if field_attrs.opaque => quote! {
+                ::safe_bytes::__fill(field_bytes, 0xfe);
+                padding += field_bytes.len();
+            },
+            Some((elem, len)) => {
+                let indices = (0..len).map(proc_macro2::Literal::usize_unsuffixed);
+                quote! {
+                    if let ::safe_bytes::core::option::Option::Some(elem_fields) = #name.sub() {
+                        #(
+                            padding += <#elem as ::safe_bytes::PaddingBane>::init_padding_counted(
+                                elem_fields,
+                                ::safe_bytes::__element_bytes::<#elem>(field_bytes, #indices),
+                            );
+                        )*
+                    }
+                }
+            }
+            None => quote! {
+                padding += <#ty as ::safe_bytes::PaddingBane>::init_padding_counted(#name.sub(), field_bytes);
+            },
+        })
+        .collect::<Vec<_>>();
+
+    // Bound every field type so that missing implementation
+    // is reported at the field instead of the generated code.
+    let mut generics = ast.generics.clone();
+    let predicates = &mut generics.make_where_clause().predicates;
+    for (ty, field_attrs) in field_types.iter().zip(field_attrs) {
+        predicates.push(syn::parse_quote_spanned!(ty.span()=> #ty: ::safe_bytes::PaddingBane));
+        if field_attrs.opaque {
+            // Any bytes must be valid value of the field, as its data is overwritten.
+            predicates
+                .push(syn::parse_quote_spanned!(ty.span()=> #ty: ::safe_bytes::FromSafeBytes));
+        }
+    }
+    let (impl_generics, type_generics, where_clause) = generics.split_for_impl();
+
+    // Higher-ranked bounds are checked only when the impl is used,
+    // so fields that don't implement `StaticFields` don't cause errors.
+    let mut static_generics = generics.clone();
+    let predicates = &mut static_generics.make_where_clause().predicates;
+    for ty in &field_types {
+        predicates.push(syn::parse_quote!(for<'__safe_bytes> #ty: ::safe_bytes::StaticFields));
+    }
+    let static_where_clause = &static_generics.where_clause;
+
+    // Opaque fields are filled even without padding.
+    let needs_padding = if field_attrs.iter().any(|a| a.opaque) {
+        quote!(true)
+    } else {
+        quote! {
+            !<Self as ::safe_bytes::PaddingBane>::PADDING_MAP.is_empty()
+                #(|| <#field_types as ::safe_bytes::PaddingBane>::NEEDS_PADDING)*
+        }
+    };
+
+    let size_assertions = attrs.assert_size.iter().map(|size| {
+        let message = format!("size of `{}` must be {} bytes", type_name, size);
+        quote_spanned! {size.span()=>
+            ::safe_bytes::core::assert!(
+                ::safe_bytes::core::mem::size_of::<Self>() == #size,
+                #message,
+            );
+        }
+    });
+    let padding_assertions = fields
+        .iter()
+        .zip(&field_members)
+        .zip(field_attrs)
+        .filter(|(_, field_attrs)| field_attrs.deny_padding)
+        .map(|((f, member), _)| {
+            let ty = &f.ty;
+            let message = format!(
+                "field `{}` of `{}` must not have padding",
+                quote!(#member),
+                type_name,
+            );
+            quote_spanned! {ty.span()=>
+                ::safe_bytes::core::assert!(
+                    !<#ty as ::safe_bytes::PaddingBane>::NEEDS_PADDING,
+                    #message,
+                );
+            }
+        });
+    // Fields the derive can't swap must not be affected by byte order.
+    let byteorder_assertions = field_types
+        .iter()
+        .zip(&field_members)
+        .filter(|(ty, _)| attrs.byteorder && numeric_element(ty).is_none())
+        .map(|(ty, member)| {
+            let elem = array_element(ty);
+            let message = format!(
+                "field `{}` of `{}` has no known byte order, \
+                 only integer and float fields and arrays of them may be wider than a byte",
+                quote!(#member),
+                type_name,
+            );
+            quote_spanned! {ty.span()=>
+                ::safe_bytes::core::assert!(
+                    ::safe_bytes::core::mem::size_of::<#elem>() <= 1,
+                    #message,
+                );
+            }
+        });
+    let assertions = size_assertions
+        .chain(padding_assertions)
+        .chain(byteorder_assertions)
+        .collect();
+    let (assertions, check) = static_assertions(ast, &generics, assertions);
+
+    let declaration_order = if attrs.declaration_order {
+        let vis = &ast.vis;
+        quote! {
+            impl #impl_generics #type_name #type_generics #where_clause {
+                /// Size of the bytes written by `declaration_order_bytes`.
+                #vis const DECLARATION_ORDER_SIZE: usize = 0 #(+ ::safe_bytes::core::mem::size_of::<#field_types>())*;
+
+                /// Copies bytes of the fields into `buffer` in declaration order
+                /// without padding between them and returns written bytes.
+                ///
+                /// # Panics
+                ///
+                /// Panics if `buffer` is shorter than `DECLARATION_ORDER_SIZE`.
+                #inline
+                #vis fn declaration_order_bytes<'a>(&mut self, buffer: &'a mut [u8]) -> &'a [u8] {
+                    let mut offset = 0;
+                    #(
+                        let bytes = ::safe_bytes::SafeBytes::safe_bytes(&mut self.#field_members);
+                        buffer[offset..offset + bytes.len()].copy_from_slice(bytes);
+                        offset += bytes.len();
+                    )*
+                    &buffer[..offset]
+                }
+            }
+        }
+    } else {
+        TokenStream::new()
+    };
+
+    let no_tail = if attrs.no_tail_pad {
+        let vis = &ast.vis;
+        quote! {
+            impl #impl_generics #type_name #type_generics #where_clause {
+                /// Size of the bytes returned by `safe_bytes_no_tail`,
+                /// i.e. offset of the trailing padding.
+                #vis const NO_TAIL_SIZE: usize = {
+                    let (gaps, count) = <Self as ::safe_bytes::__PaddingGaps<#gap_count>>::GAPS;
+                    let size = ::safe_bytes::core::mem::size_of::<Self>();
+                    if count > 0 && gaps[count - 1].0 + gaps[count - 1].1 == size {
+                        gaps[count - 1].0
+                    } else {
+                        size
+                    }
+                };
+
+                /// Returns bytes of the value without trailing padding,
+                /// skipping its initialization.
+                /// Padding between and inside the fields is initialized as usual.
+                #inline
+                #vis fn safe_bytes_no_tail(&mut self) -> &[u8] {
+                    unsafe {
+                        // All padding before the trailing one is initialized.
+                        ::safe_bytes::__safe_bytes_with(self, Self::NO_TAIL_SIZE, |fields, bytes| {
+                            let (#(#field_names,)*) = fields;
+                            let mut padding = 0;
+                            for &(offset, count) in <Self as ::safe_bytes::PaddingBane>::PADDING_MAP {
+                                if offset < Self::NO_TAIL_SIZE {
+                                    #fill_gap
+                                }
+                            }
+
+                            #(
+                                let field_bytes = &mut bytes[#field_names.raw().offset() .. #field_names.raw().end()];
+                                #field_fills
+                            )*
+
+                            let _ = padding;
+                        })
+                    }
+                }
+            }
+        }
+    } else {
+        TokenStream::new()
+    };
+
+    let byteorder = if attrs.byteorder {
+        let vis = &ast.vis;
+        let swaps = field_types
+            .iter()
+            .zip(&field_members)
+            .filter_map(|(ty, member)| {
+                let elem = numeric_element(ty)?;
+                Some(quote! {
+                    let offset = ::safe_bytes::__offset_of!(Self, #member);
+                    let field_bytes = &mut bytes[offset..offset + ::safe_bytes::core::mem::size_of::<#ty>()];
+                    for elem_bytes in field_bytes.chunks_exact_mut(::safe_bytes::core::mem::size_of::<#elem>()) {
+                        elem_bytes.reverse();
+                    }
+                })
+            })
+            .collect::<Vec<_>>();
+
+        quote! {
+            impl #impl_generics #type_name #type_generics #where_clause {
+                /// Copies bytes of the value into `buffer`
+                /// with numeric fields in little-endian byte order and returns them.
+                ///
+                /// # Panics
+                ///
+                /// Panics if `buffer` is shorter than the value.
+                #inline
+                #vis fn to_le_safe_bytes<'a>(&mut self, buffer: &'a mut [u8]) -> &'a [u8] {
+                    let bytes = &mut buffer[..::safe_bytes::core::mem::size_of::<Self>()];
+                    bytes.copy_from_slice(::safe_bytes::SafeBytes::safe_bytes(self));
+                    #[cfg(target_endian = "big")]
+                    {
+                        #(#swaps)*
+                    }
+                    bytes
+                }
+
+                /// Copies bytes of the value into `buffer`
+                /// with numeric fields in big-endian byte order and returns them.
+                ///
+                /// # Panics
+                ///
+                /// Panics if `buffer` is shorter than the value.
+                #inline
+                #vis fn to_be_safe_bytes<'a>(&mut self, buffer: &'a mut [u8]) -> &'a [u8] {
+                    let bytes = &mut buffer[..::safe_bytes::core::mem::size_of::<Self>()];
+                    bytes.copy_from_slice(::safe_bytes::SafeBytes::safe_bytes(self));
+                    #[cfg(target_endian = "little")]
+                    {
+                        #(#swaps)*
+                    }
+                    bytes
+                }
+            }
+        }
+    } else {
+        TokenStream::new()
+    };
+
+    let layout_const = if attrs.layout_const {
+        let vis = &ast.vis;
+        let names = field_members.iter().map(|member| match member {
+            syn::Member::Named(ident) => ident.to_string(),
+            syn::Member::Unnamed(index) => index.index.to_string(),
+        });
+        quote! {
+            impl #impl_generics #type_name #type_generics #where_clause {
+                /// Name, offset and size of each field in declaration order.
+                #vis const LAYOUT: &'static [(&'static str, usize, usize)] = &[#((
+                    #names,
+                    ::safe_bytes::__offset_of!(Self, #field_members),
+                    ::safe_bytes::core::mem::size_of::<#field_types>(),
+                ),)*];
+            }
+        }
+    } else {
+        TokenStream::new()
+    };
+
+    let portable = if attrs.portable {
+        let mut generics = ast.generics.clone();
+        let predicates = &mut generics.make_where_clause().predicates;
+        for ty in &field_types {
+            predicates
+                .push(syn::parse_quote_spanned!(ty.span()=> #ty: ::safe_bytes::PortableBytes));
+        }
+        let (impl_generics, type_generics, where_clause) = generics.split_for_impl();
+
+        quote! {
+            #[automatically_derived]
+            impl #impl_generics ::safe_bytes::PortableBytes for #type_name #type_generics #where_clause {
+                const PORTABLE_SIZE: usize = 0 #(+ <#field_types as ::safe_bytes::PortableBytes>::PORTABLE_SIZE)*;
+
+                #inline
+                fn write_portable(&self, out: &mut [u8]) {
+                    ::safe_bytes::core::assert_eq!(out.len(), Self::PORTABLE_SIZE);
+                    let mut offset = 0;
+                    #(
+                        let size = <#field_types as ::safe_bytes::PortableBytes>::PORTABLE_SIZE;
+                        ::safe_bytes::PortableBytes::write_portable(&self.#field_members, &mut out[offset..offset + size]);
+                        offset += size;
+                    )*
+                    let _ = offset;
+                }
+            }
+        }
+    } else {
+        TokenStream::new()
+    };
+
+    // Fieldless structs return `()` from the empty body,
+    // as unit expression would trigger `clippy::unused_unit` in user code.
+    let (get_fields, static_fields) = if field_members.is_empty() {
+        (TokenStream::new(), TokenStream::new())
+    } else {
+        (
+            quote! {
+                (#(::safe_bytes::TypedField::new(
+                    ::safe_bytes::Field::new(
+                        ::safe_bytes::__offset_of!(Self, #field_members),
+                        ::safe_bytes::core::mem::size_of::<#field_types>(),
+                    ),
+                    ::safe_bytes::PaddingBane::get_fields(&self.#field_members),
+                ),)*)
+            },
+            quote! {
+                (#(::safe_bytes::TypedField::new(
+                    ::safe_bytes::Field::new(
+                        ::safe_bytes::__offset_of!(Self, #field_members),
+                        ::safe_bytes::core::mem::size_of::<#field_types>(),
+                    ),
+                    <#field_types as ::safe_bytes::StaticFields>::fields(),
+                ),)*)
+            },
+        )
+    };
 
     quote! {
+        #assertions
+
+        #declaration_order
+
+        #no_tail
+
+        #portable
+
+        #byteorder
+
+        #layout_const
+
         #[automatically_derived]
         unsafe impl #impl_generics ::safe_bytes::PaddingBane for #type_name #type_generics #where_clause {
             type Fields = (#(::safe_bytes::TypedField<#field_types>,)*);
 
-            #[inline(always)]
+            const PADDING_MAP: &'static [(usize, usize)] = {
+                let gaps: &'static (_, usize) = &<Self as ::safe_bytes::__PaddingGaps<#gap_count>>::GAPS;
+                gaps.0.split_at(gaps.1).0
+            };
+
+            const NEEDS_PADDING: bool = #needs_padding;
+
+            #inline_always
             fn get_fields(&self) -> Self::Fields {
-                (#(::safe_bytes::typed_field!(*self, #type_name, #field_names),)*)
+                #check
+                #get_fields
             }
 
-            #[inline]
-            unsafe fn init_padding(fields: Self::Fields, bytes: &mut [::safe_bytes::core::mem::MaybeUninit<u8>]) {
-                use {
-                    ::safe_bytes::core::{mem::size_of, ptr::write_bytes},
-                };
-
+            #inline_always
+            fn field(fields: Self::Fields, index: usize) -> ::safe_bytes::core::option::Option<::safe_bytes::Field> {
                 let (#(#field_names,)*) = fields;
-                let mut raw_fields = [#(#field_names.raw,)*];
-                raw_fields.sort_unstable_by_key(|f| f.offset);
-                let mut offset = 0;
-                for field in &raw_fields {
-                    if field.offset > offset {
-                        let count = field.offset - offset;
-                        write_bytes(&mut bytes[offset], 0xfe, count);
-                    }
-                    offset = field.offset + field.size;
+                match index {
+                    #(#field_indices => ::safe_bytes::core::option::Option::Some(#field_names.raw()),)*
+                    _ => ::safe_bytes::core::option::Option::None,
                 }
+            }
+
+            #inline
+            unsafe fn init_padding(fields: Self::Fields, bytes: &mut [::safe_bytes::core::mem::MaybeUninit<u8>]) {
+                Self::init_padding_counted(fields, bytes);
+            }
 
-                if size_of::<Self>() > offset {
-                    let count = size_of::<Self>() - offset;
-                    write_bytes(&mut bytes[offset], 0xfe, count);
+            #inline
+            unsafe fn init_padding_counted(fields: Self::Fields, bytes: &mut [::safe_bytes::core::mem::MaybeUninit<u8>]) -> usize {
+                // Constant condition, the body is removed for dense structs.
+                if !<Self as ::safe_bytes::PaddingBane>::NEEDS_PADDING {
+                    return 0;
                 }
 
+                let (#(#field_names,)*) = fields;
+                #gaps
+
                 #(
-                    let field_bytes = &mut bytes[#field_names.raw.offset .. #field_names.raw.offset + #field_names.raw.size];
-                    <#field_types as ::safe_bytes::PaddingBane>::init_padding(#field_names.sub, field_bytes);
+                    let field_bytes = &mut bytes[#field_names.raw().offset() .. #field_names.raw().end()];
+                    #field_fills
                 )*
+
+                padding
+            }
+        }
+
+        #[automatically_derived]
+        unsafe impl #impl_generics ::safe_bytes::StaticFields for #type_name #type_generics #static_where_clause {
+            #inline_always
+            fn fields() -> Self::Fields {
+                #check
+                #static_fields
+            }
+        }
+
+        #[automatically_derived]
+        impl #impl_generics ::safe_bytes::__PaddingGaps<#gap_count> for #type_name #type_generics #where_clause {
+            const GAPS: ([(usize, usize); #gap_count], usize) = ::safe_bytes::__padding_gaps::<#field_count, #gap_count>(
+                [#(::safe_bytes::Field::new(
+                    ::safe_bytes::__offset_of!(Self, #field_members),
+                    ::safe_bytes::core::mem::size_of::<#field_types>(),
+                ),)*],
+                ::safe_bytes::core::mem::size_of::<Self>(),
+            );
+        }
+    }
+}
+
+fn impl_union(
+    ast: &syn::DeriveInput,
+    attrs: &ContainerAttrs,
+    fields: &syn::FieldsNamed,
+) -> TokenStream {
+    let type_name = &ast.ident;
+    let inline_always = attrs.inline_always();
+
+    // Members are bounded so that their padding can be checked.
+    let mut generics = ast.generics.clone();
+    let predicates = &mut generics.make_where_clause().predicates;
+    for f in &fields.named {
+        let ty = &f.ty;
+        predicates.push(syn::parse_quote_spanned!(ty.span()=> #ty: ::safe_bytes::PaddingBane));
+    }
+
+    let assertions = fields.named.iter().map(|f| {
+        let ty = &f.ty;
+        let size_message = format!(
+            "size of union `{}` must be equal to size of its field `{}`",
+            type_name,
+            f.ident.as_ref().unwrap(),
+        );
+        let padding_message = format!(
+            "field `{}` of union `{}` must not have padding",
+            f.ident.as_ref().unwrap(),
+            type_name,
+        );
+        quote_spanned! {ty.span()=>
+            ::safe_bytes::core::assert!(
+                ::safe_bytes::core::mem::size_of::<Self>() == ::safe_bytes::core::mem::size_of::<#ty>(),
+                #size_message,
+            );
+            ::safe_bytes::core::assert!(
+                !<#ty as ::safe_bytes::PaddingBane>::NEEDS_PADDING,
+                #padding_message,
+            );
+        }
+    });
+    let (assertions, check) = static_assertions(ast, &generics, assertions.collect());
+
+    let (impl_generics, type_generics, where_clause) = generics.split_for_impl();
+
+    quote! {
+        #assertions
+
+        #[automatically_derived]
+        unsafe impl #impl_generics ::safe_bytes::PaddingBane for #type_name #type_generics #where_clause {
+            type Fields = ();
+
+            // Active member has no padding and covers all bytes.
+            const NEEDS_PADDING: bool = false;
+
+            #inline_always
+            fn get_fields(&self) -> Self::Fields {
+                #check
+            }
+
+            #inline_always
+            unsafe fn init_padding(_fields: (), _bytes: &mut [::safe_bytes::core::mem::MaybeUninit<u8>]) {}
+        }
+
+        #[automatically_derived]
+        unsafe impl #impl_generics ::safe_bytes::StaticFields for #type_name #type_generics #where_clause {
+            #inline_always
+            fn fields() -> Self::Fields {
+                #check
             }
         }
     }
 }
+
+fn impl_enum(
+    ast: &syn::DeriveInput,
+    attrs: &ContainerAttrs,
+    dataenum: &syn::DataEnum,
+) -> syn::Result<TokenStream> {
+    let type_name = &ast.ident;
+    let inline_always = attrs.inline_always();
+
+    if attrs.union_all_init
+        || attrs.pad_per_field
+        || attrs.pad_with_fn.is_some()
+        || attrs.no_tail_pad
+        || attrs.declaration_order
+        || attrs.portable
+        || attrs.byteorder
+        || attrs.layout_const
+    {
+        return Err(syn::Error::new(
+            dataenum.enum_token.span,
+            "only `no_inline` and `assert_size` attributes are supported for enums",
+        ));
+    }
+
+    if let Some(variant) = dataenum.variants.iter().find(|v| !v.fields.is_empty()) {
+        return Err(syn::Error::new_spanned(
+            &variant.fields,
+            "safe_bytes can be derived only for fieldless enums",
+        ));
+    }
+
+    let discriminant = match enum_repr(&ast.attrs)? {
+        Some(discriminant) => discriminant,
+        None => return Err(syn::Error::new(
+            dataenum.enum_token.span,
+            "safe_bytes can be derived only for enums with `#[repr(C)]` or integer representation",
+        )),
+    };
+
+    let mut assertions: Vec<_> = attrs
+        .assert_size
+        .iter()
+        .map(|size| {
+            let message = format!("size of `{}` must be {} bytes", type_name, size);
+            quote_spanned! {size.span()=>
+                ::safe_bytes::core::assert!(
+                    ::safe_bytes::core::mem::size_of::<Self>() == #size,
+                    #message,
+                );
+            }
+        })
+        .collect();
+
+    // Size of `#[repr(C)]` alone is the size of its discriminant.
+    if let Some(int) = discriminant {
+        let message = format!(
+            "size of `{}` must be equal to size of its discriminant `{}`",
+            type_name, int,
+        );
+        assertions.push(quote_spanned! {int.span()=>
+            ::safe_bytes::core::assert!(
+                ::safe_bytes::core::mem::size_of::<Self>() == ::safe_bytes::core::mem::size_of::<#int>(),
+                #message,
+            );
+        });
+    }
+    let (assertions, check) = static_assertions(ast, &ast.generics, assertions);
+
+    let (impl_generics, type_generics, where_clause) = ast.generics.split_for_impl();
+
+    // Fieldless enum is represented by its discriminant alone, which has no padding.
+    Ok(quote! {
+        #assertions
+
+        #[automatically_derived]
+        unsafe impl #impl_generics ::safe_bytes::PaddingBane for #type_name #type_generics #where_clause {
+            type Fields = ();
+
+            const NEEDS_PADDING: bool = false;
+
+            #inline_always
+            fn get_fields(&self) -> Self::Fields {
+                #check
+            }
+
+            #inline_always
+            unsafe fn init_padding(_fields: (), _bytes: &mut [::safe_bytes::core::mem::MaybeUninit<u8>]) {}
+        }
+
+        #[automatically_derived]
+        unsafe impl #impl_generics ::safe_bytes::StaticFields for #type_name #type_generics #where_clause {
+            #inline_always
+            fn fields() -> Self::Fields {
+                #check
+            }
+        }
+    })
+}
+
+/// Checks that `#[repr(...)]` attributes specify `C` or primitive integer.
+/// Returns `None` if they don't and integer type if one is specified.
+/// Changing alignment is rejected, as it adds padding after the discriminant.
+fn enum_repr(attrs: &[syn::Attribute]) -> syn::Result<Option<Option<syn::Ident>>> {
+    const INTS: &[&str] = &[
+        "u8", "i8", "u16", "i16", "u32", "i32", "u64", "i64", "u128", "i128", "usize", "isize",
+    ];
+
+    let mut c = false;
+    let mut int = None;
+    for attr in attrs.iter().filter(|attr| attr.path.is_ident("repr")) {
+        if let syn::Meta::List(list) = attr.parse_meta()? {
+            for nested in &list.nested {
+                let path = match nested {
+                    syn::NestedMeta::Meta(meta) => meta.path(),
+                    syn::NestedMeta::Lit(_) => continue,
+                };
+                if path.is_ident("align") || path.is_ident("packed") {
+                    return Err(syn::Error::new_spanned(
+                        nested,
+                        "safe_bytes cannot be derived for enums with `align` or `packed` representation",
+                    ));
+                }
+                if path.is_ident("C") {
+                    c = true;
+                } else if let Some(ident) = path
+                    .get_ident()
+                    .filter(|ident| INTS.iter().any(|i| *ident == i))
+                {
+                    int = Some(ident.clone());
+                }
+            }
+        }
+    }
+
+    if int.is_some() || c {
+        Ok(Some(int))
+    } else {
+        Ok(None)
+    }
+}
+
+/// Reports reference fields at the field,
+/// instead of generic error about missing `PaddingBane` implementation.
+fn reject_references(fields: &syn::Fields) -> syn::Result<()> {
+    let mut errors = fields.iter().filter_map(|f| match &f.ty {
+        syn::Type::Reference(reference) => Some(syn::Error::new_spanned(
+            reference,
+            "reference fields are not supported by `SafeBytes`, \
+             use raw pointer field, e.g. `*const T`, instead",
+        )),
+        _ => None,
+    });
+
+    match errors.next() {
+        None => Ok(()),
+        Some(mut error) => {
+            errors.for_each(|e| error.combine(e));
+            Err(error)
+        }
+    }
+}
+
+/// Reports `usize` and `isize` in field types,
+/// including elements of arrays and tuples and generic arguments.
+/// Type aliases can't be seen through.
+fn reject_platform_ints(fields: &syn::Fields) -> syn::Result<()> {
+    let mut types = Vec::new();
+    for f in fields {
+        platform_ints(&f.ty, &mut types);
+    }
+
+    let mut errors = types.into_iter().map(|ty| {
+        syn::Error::new_spanned(
+            ty,
+            "platform-width integers are forbidden by `no_platform_ints`, \
+             use fixed-size integer, e.g. `u64`, instead",
+        )
+    });
+
+    match errors.next() {
+        None => Ok(()),
+        Some(mut error) => {
+            errors.for_each(|e| error.combine(e));
+            Err(error)
+        }
+    }
+}
+
+/// Collects `usize` and `isize` types found in the type.
+fn platform_ints<'a>(ty: &'a syn::Type, found: &mut Vec<&'a syn::Type>) {
+    match ty {
+        syn::Type::Array(array) => platform_ints(&array.elem, found),
+        syn::Type::Group(group) => platform_ints(&group.elem, found),
+        syn::Type::Paren(paren) => platform_ints(&paren.elem, found),
+        syn::Type::Tuple(tuple) => tuple.elems.iter().for_each(|ty| platform_ints(ty, found)),
+        syn::Type::Path(path) if path.qself.is_none() => {
+            let last = path.path.segments.last();
+            if let Some(last) = last {
+                if last.arguments.is_empty() && (last.ident == "usize" || last.ident == "isize") {
+                    found.push(ty);
+                }
+                if let syn::PathArguments::AngleBracketed(args) = &last.arguments {
+                    for arg in &args.args {
+                        if let syn::GenericArgument::Type(ty) = arg {
+                            platform_ints(ty, found);
+                        }
+                    }
+                }
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Returns integer or float type of the field,
+/// or of its array elements, whose bytes are swapped to change byte order.
+/// Single byte types have no byte order.
+fn numeric_element(ty: &syn::Type) -> Option<&syn::Type> {
+    match ty {
+        syn::Type::Array(array) => numeric_element(&array.elem),
+        syn::Type::Group(group) => numeric_element(&group.elem),
+        syn::Type::Paren(paren) => numeric_element(&paren.elem),
+        syn::Type::Path(path) if path.qself.is_none() => {
+            const NUMERIC: &[&str] = &[
+                "u16", "u32", "u64", "u128", "usize", "i16", "i32", "i64", "i128", "isize", "f32",
+                "f64",
+            ];
+            let segments = &path.path.segments;
+            let ident = &segments.last()?.ident;
+
+            // Bare name or `core::primitive::*` and `std::primitive::*` paths.
+            let primitive = match segments.len() {
+                1 => path.path.leading_colon.is_none(),
+                3 => {
+                    (segments[0].ident == "core" || segments[0].ident == "std")
+                        && segments[1].ident == "primitive"
+                }
+                _ => false,
+            };
+            if primitive
+                && segments.iter().all(|s| s.arguments.is_empty())
+                && NUMERIC.iter().any(|name| ident == name)
+            {
+                Some(ty)
+            } else {
+                None
+            }
+        }
+        _ => None,
+    }
+}
+
+/// Returns innermost element type of possibly nested array type.
+fn array_element(ty: &syn::Type) -> &syn::Type {
+    match ty {
+        syn::Type::Array(array) => array_element(&array.elem),
+        syn::Type::Group(group) => array_element(&group.elem),
+        syn::Type::Paren(paren) => array_element(&paren.elem),
+        _ => ty,
+    }
+}
+
+/// Returns element type and length of array type
+/// with literal length from 1 to `max_len`.
+fn unrolled_array(ty: &syn::Type, max_len: usize) -> Option<(&syn::Type, usize)> {
+    match ty {
+        syn::Type::Array(array) => match &array.len {
+            syn::Expr::Lit(syn::ExprLit {
+                lit: syn::Lit::Int(len),
+                ..
+            }) => {
+                let len = len.base10_parse().ok()?;
+                if len > 0 && len <= max_len {
+                    Some((&array.elem, len))
+                } else {
+                    None
+                }
+            }
+            _ => None,
+        },
+        syn::Type::Group(group) => unrolled_array(&group.elem, max_len),
+        syn::Type::Paren(paren) => unrolled_array(&paren.elem, max_len),
+        _ => None,
+    }
+}
+
+/// Emits assertions that are checked at compile time.
+///
+/// Assertions may refer to the type as `Self`.
+/// For non-generic types they are checked unconditionally.
+/// Generic types can't be named in free constant,
+/// so assertions are placed into hidden associated constant
+/// which is checked when returned expression is used in the generated impl.
+/// Its impl block takes `generics`, so assertions may rely on bounds of the generated impl.
+fn static_assertions(
+    ast: &syn::DeriveInput,
+    generics: &syn::Generics,
+    assertions: Vec<TokenStream>,
+) -> (TokenStream, TokenStream) {
+    let type_name = &ast.ident;
+
+    if assertions.is_empty() {
+        return (TokenStream::new(), TokenStream::new());
+    }
+
+    if ast.generics.params.is_empty() {
+        let items = quote! {
+            const _: () = {
+                impl #type_name {
+                    #[doc(hidden)]
+                    const __SAFE_BYTES_ASSERTIONS: () = {
+                        #(#assertions)*
+                    };
+                }
+                #type_name::__SAFE_BYTES_ASSERTIONS
+            };
+        };
+        (items, TokenStream::new())
+    } else {
+        let (impl_generics, type_generics, where_clause) = generics.split_for_impl();
+        let items = quote! {
+            impl #impl_generics #type_name #type_generics #where_clause {
+                #[doc(hidden)]
+                const __SAFE_BYTES_ASSERTIONS: () = {
+                    #(#assertions)*
+                };
+            }
+        };
+        let check = quote! {
+            let () = Self::__SAFE_BYTES_ASSERTIONS;
+        };
+        (items, check)
+    }
+}
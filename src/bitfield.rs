@@ -0,0 +1,114 @@
+//! Emulation of C bitfields.
+
+use {
+    crate::{PaddingBane, StaticFields},
+    core::mem::MaybeUninit,
+};
+
+/// Backing integer for C-like bitfields.
+///
+/// C structs often pack several small fields into bits of single integer.
+/// `BitField` keeps those bits in backing integer `T`
+/// and provides access to bit ranges,
+/// while serializing exactly as the backing integer.
+///
+/// Bit ranges API is provided for unsigned integers
+/// `u8`, `u16`, `u32`, `u64` and `u128`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[repr(transparent)]
+pub struct BitField<T>(T);
+
+impl<T> BitField<T> {
+    /// Wraps backing integer.
+    #[inline(always)]
+    pub const fn new(bits: T) -> Self {
+        BitField(bits)
+    }
+
+    /// Unwraps backing integer.
+    #[inline(always)]
+    pub fn into_bits(self) -> T {
+        self.0
+    }
+}
+
+macro_rules! impl_bitfield {
+    ($($t:ty)*) => {$(
+        impl BitField<$t> {
+            /// Returns `width` bits starting from bit `offset`.
+            ///
+            /// # Panics
+            ///
+            /// Panics if bit range doesn't fit into backing integer.
+            #[inline]
+            pub fn get(&self, offset: u32, width: u32) -> $t {
+                let mask = Self::mask(offset, width);
+                (self.0 & mask) >> offset
+            }
+
+            /// Sets `width` bits starting from bit `offset`.
+            /// Excessive high bits of the `value` are discarded.
+            ///
+            /// # Panics
+            ///
+            /// Panics if bit range doesn't fit into backing integer.
+            #[inline]
+            pub fn set(&mut self, offset: u32, width: u32, value: $t) {
+                let mask = Self::mask(offset, width);
+                self.0 = (self.0 & !mask) | ((value << offset) & mask);
+            }
+
+            #[inline(always)]
+            fn mask(offset: u32, width: u32) -> $t {
+                assert!(
+                    offset < <$t>::BITS && width <= <$t>::BITS - offset,
+                    "Bit range doesn't fit into backing integer",
+                );
+
+                if width == 0 {
+                    0
+                } else {
+                    (<$t>::MAX >> (<$t>::BITS - width)) << offset
+                }
+            }
+        }
+    )*};
+}
+
+impl_bitfield!(u8 u16 u32 u64 u128);
+
+unsafe impl<T> PaddingBane for BitField<T>
+where
+    T: PaddingBane,
+{
+    type Fields = T::Fields;
+
+    const PADDING_MAP: &'static [(usize, usize)] = T::PADDING_MAP;
+
+    const NEEDS_PADDING: bool = T::NEEDS_PADDING;
+
+    #[inline(always)]
+    fn get_fields(&self) -> Self::Fields {
+        self.0.get_fields()
+    }
+
+    #[inline(always)]
+    unsafe fn init_padding(fields: Self::Fields, bytes: &mut [MaybeUninit<u8>]) {
+        T::init_padding(fields, bytes);
+    }
+
+    #[inline(always)]
+    unsafe fn init_padding_counted(fields: Self::Fields, bytes: &mut [MaybeUninit<u8>]) -> usize {
+        T::init_padding_counted(fields, bytes)
+    }
+}
+
+unsafe impl<T> StaticFields for BitField<T>
+where
+    T: StaticFields,
+{
+    #[inline(always)]
+    fn fields() -> Self::Fields {
+        T::fields()
+    }
+}
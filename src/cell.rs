@@ -0,0 +1,120 @@
+use {
+    crate::{PaddingBane, SafeBytes},
+    core::{
+        mem::{size_of, MaybeUninit},
+        ptr::copy_nonoverlapping,
+        slice::from_raw_parts,
+    },
+};
+
+/// Value with cached bytes representation.
+///
+/// Bytes representation is copied into separate buffer
+/// and reused until value is changed.
+/// Every mutable access to the value through [`SafeBytesCell::get_mut`]
+/// invalidates the cache by bumping generation counter.
+/// Changes made through shared reference,
+/// i.e. using interior mutability, are not tracked.
+/// Call [`SafeBytesCell::bump`] after them.
+///
+/// Forgetting to call [`SafeBytesCell::bump`] is a logic error
+/// that leads to stale bytes returned from [`SafeBytesCell::as_bytes`],
+/// but never to undefined behavior, as cached bytes are always initialized copy.
+///
+/// [`SafeBytesCell::get_mut`]: ./struct.SafeBytesCell.html#method.get_mut
+/// [`SafeBytesCell::bump`]: ./struct.SafeBytesCell.html#method.bump
+/// [`SafeBytesCell::as_bytes`]: ./struct.SafeBytesCell.html#method.as_bytes
+pub struct SafeBytesCell<T> {
+    value: T,
+
+    /// Storage for bytes of the value.
+    /// Never treated as `T`.
+    cache: MaybeUninit<T>,
+
+    /// Generation of the value.
+    generation: u64,
+
+    /// Generation of the value when bytes were copied into cache.
+    cached: Option<u64>,
+
+    /// Number of cached bytes.
+    len: usize,
+}
+
+impl<T> SafeBytesCell<T> {
+    /// Wraps value into cell.
+    #[inline]
+    pub fn new(value: T) -> Self {
+        SafeBytesCell {
+            value,
+            cache: MaybeUninit::uninit(),
+            generation: 0,
+            cached: None,
+            len: 0,
+        }
+    }
+
+    /// Returns shared reference to the value.
+    #[inline]
+    pub fn get(&self) -> &T {
+        &self.value
+    }
+
+    /// Returns mutable reference to the value,
+    /// invalidating cached bytes.
+    #[inline]
+    pub fn get_mut(&mut self) -> &mut T {
+        self.bump();
+        &mut self.value
+    }
+
+    /// Unwraps the value.
+    #[inline]
+    pub fn into_inner(self) -> T {
+        self.value
+    }
+
+    /// Invalidates cached bytes.
+    #[inline]
+    pub fn bump(&mut self) {
+        self.generation = self.generation.wrapping_add(1);
+    }
+
+    /// Returns current generation of the value.
+    #[inline]
+    pub fn generation(&self) -> u64 {
+        self.generation
+    }
+}
+
+impl<T> SafeBytesCell<T>
+where
+    T: PaddingBane,
+{
+    /// Returns bytes representation of the value.
+    ///
+    /// Padding is initialized and bytes are copied into cache
+    /// only if generation changed since last call.
+    pub fn as_bytes(&mut self) -> &[u8] {
+        if self.cached != Some(self.generation) {
+            let bytes = self.value.safe_bytes();
+            assert!(bytes.len() <= size_of::<T>());
+
+            unsafe {
+                // Cache is `size_of::<T>()` bytes long.
+                copy_nonoverlapping(
+                    bytes.as_ptr(),
+                    self.cache.as_mut_ptr() as *mut u8,
+                    bytes.len(),
+                );
+            }
+            self.len = bytes.len();
+            self.cached = Some(self.generation);
+        }
+
+        unsafe {
+            // First `len` bytes of the cache were initialized by copying bytes above.
+            from_raw_parts(self.cache.as_ptr() as *const u8, self.len)
+        }
+    }
+}
@@ -0,0 +1,88 @@
+use {
+    crate::{Field, PaddingBane, SafeBytes},
+    alloc::{string::String, vec::Vec},
+    core::fmt::{Display, Write as _},
+};
+
+/// Renders bytes of the value as hex dump split into regions
+/// of fields and padding between them.
+///
+/// Each region starts with its offset and ends with label:
+/// index of the field as reported by [`PaddingBane::field`] or `pad`.
+/// Types that report no fields are rendered as single unlabeled region.
+///
+/// ```
+/// # use safe_bytes::{debug_bytes, SafeBytes};
+/// #[derive(SafeBytes)]
+/// #[repr(C)]
+/// struct Example {
+///     a: u8,
+///     b: u16,
+/// }
+///
+/// let dump = debug_bytes(&mut Example { a: 1, b: 0x0202 });
+/// assert_eq!(dump, "00: 01 [0] | 01: fe [pad] | 02: 02 02 [1]");
+/// ```
+///
+/// [`PaddingBane::field`]: ./trait.PaddingBane.html#method.field
+pub fn debug_bytes<T>(value: &mut T) -> String
+where
+    T: PaddingBane,
+{
+    let fields = value.get_fields();
+    let mut regions: Vec<(usize, Field)> = (0..)
+        .map(|index| T::field(fields, index).map(|field| (index, field)))
+        .take_while(Option::is_some)
+        .flatten()
+        .collect();
+    regions.sort_unstable_by_key(|(_, field)| field.offset());
+
+    let bytes = value.safe_bytes();
+    let mut dump = String::new();
+
+    if regions.is_empty() {
+        write_region(&mut dump, 0, bytes, None);
+        return dump;
+    }
+
+    let mut offset = 0;
+    for (index, field) in regions {
+        if field.offset() > offset {
+            write_region(
+                &mut dump,
+                offset,
+                &bytes[offset..field.offset()],
+                Some(&"pad"),
+            );
+        }
+        write_region(
+            &mut dump,
+            field.offset(),
+            &bytes[field.offset()..field.end()],
+            Some(&index),
+        );
+        offset = offset.max(field.end());
+    }
+
+    if bytes.len() > offset {
+        write_region(&mut dump, offset, &bytes[offset..], Some(&"pad"));
+    }
+
+    dump
+}
+
+/// Appends region with optional label to the dump.
+fn write_region(dump: &mut String, offset: usize, bytes: &[u8], label: Option<&dyn Display>) {
+    if !dump.is_empty() {
+        dump.push_str(" | ");
+    }
+
+    // Writing into `String` doesn't fail.
+    let _ = write!(dump, "{:02x}:", offset);
+    for byte in bytes {
+        let _ = write!(dump, " {:02x}", byte);
+    }
+    if let Some(label) = label {
+        let _ = write!(dump, " [{}]", label);
+    }
+}
@@ -0,0 +1,49 @@
+use core::fmt;
+
+/// Error returned by fallible functions of this crate.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SafeBytesError {
+    /// Output buffer can't fit the bytes.
+    BufferTooSmall {
+        /// Number of bytes to write.
+        needed: usize,
+
+        /// Length of the buffer.
+        got: usize,
+    },
+
+    /// Field with given index ends after the end of the bytes.
+    FieldOutOfBounds {
+        /// Index of the field in provided fields.
+        index: usize,
+    },
+
+    /// Two fields share some bytes.
+    FieldOverlap,
+
+    /// Length of the bytes is not equal to the size of the type.
+    SizeMismatch,
+}
+
+impl fmt::Display for SafeBytesError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SafeBytesError::BufferTooSmall { needed, got } => write!(
+                f,
+                "buffer of {} bytes is too small, {} bytes needed",
+                got, needed
+            ),
+            SafeBytesError::FieldOutOfBounds { index } => {
+                write!(f, "field {} is out of bounds", index)
+            }
+            SafeBytesError::FieldOverlap => f.write_str("fields overlap"),
+            SafeBytesError::SizeMismatch => {
+                f.write_str("length of bytes is not equal to the size of the type")
+            }
+        }
+    }
+}
+
+// `core::error::Error` is stable since 1.81.
+#[cfg(safe_bytes_core_error)]
+impl core::error::Error for SafeBytesError {}
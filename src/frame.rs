@@ -0,0 +1,70 @@
+use {
+    crate::{FromSafeBytes, SafeBytes, SafeBytesError},
+    core::{convert::TryFrom, mem::size_of},
+    std::{
+        io::{self, Read, Write},
+        string::ToString,
+        vec,
+    },
+};
+
+/// Writes bytes of the value as a frame:
+/// length of the bytes as 4-byte little-endian prefix followed by the bytes.
+/// Read the frame back with [`read_framed`].
+///
+/// Returns error of [`io::ErrorKind::InvalidInput`] kind
+/// if the value doesn't fit into `u32` length.
+///
+/// ```
+/// # use safe_bytes::{read_framed, write_framed};
+/// let mut frame = Vec::new();
+/// write_framed(&mut frame, &mut [1u8, 2, 3]).unwrap();
+/// assert_eq!(frame, [3, 0, 0, 0, 1, 2, 3]);
+///
+/// let value: [u8; 3] = read_framed(&mut &frame[..]).unwrap();
+/// assert_eq!(value, [1, 2, 3]);
+/// ```
+///
+/// [`read_framed`]: ./fn.read_framed.html
+/// [`io::ErrorKind::InvalidInput`]: https://doc.rust-lang.org/std/io/enum.ErrorKind.html#variant.InvalidInput
+pub fn write_framed<W, T>(writer: &mut W, value: &mut T) -> io::Result<()>
+where
+    W: Write + ?Sized,
+    T: SafeBytes + ?Sized,
+{
+    let bytes = value.safe_bytes();
+    let len = u32::try_from(bytes.len())
+        .map_err(|_| io::Error::new(io::ErrorKind::InvalidInput, "frame is too long"))?;
+    writer.write_all(&len.to_le_bytes())?;
+    writer.write_all(bytes)
+}
+
+/// Reads value from a frame written by [`write_framed`].
+///
+/// Returns error of [`io::ErrorKind::InvalidData`] kind
+/// if length of the frame is not equal to the size of the type.
+/// Only the length prefix is consumed in that case.
+///
+/// [`write_framed`]: ./fn.write_framed.html
+/// [`io::ErrorKind::InvalidData`]: https://doc.rust-lang.org/std/io/enum.ErrorKind.html#variant.InvalidData
+pub fn read_framed<R, T>(reader: &mut R) -> io::Result<T>
+where
+    R: Read + ?Sized,
+    T: FromSafeBytes,
+{
+    let mut len = [0; 4];
+    reader.read_exact(&mut len)?;
+
+    // Length is checked before allocating, so corrupted prefix can't request huge buffer.
+    if u32::from_le_bytes(len) as usize != size_of::<T>() {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            SafeBytesError::SizeMismatch.to_string(),
+        ));
+    }
+
+    let mut bytes = vec![0; size_of::<T>()];
+    reader.read_exact(&mut bytes)?;
+    T::from_safe_bytes(&bytes)
+        .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err.to_string()))
+}
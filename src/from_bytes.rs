@@ -0,0 +1,196 @@
+use {
+    crate::{PaddingBane, SafeBytes, SafeBytesError},
+    core::{
+        cmp::Reverse,
+        marker::{PhantomData, PhantomPinned},
+        mem::{size_of, ManuallyDrop, MaybeUninit},
+        num::{
+            NonZeroI128, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI8, NonZeroIsize, NonZeroU128,
+            NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8, NonZeroUsize, Wrapping,
+        },
+        ptr::read_unaligned,
+        sync::atomic::{
+            AtomicI16, AtomicI32, AtomicI64, AtomicI8, AtomicIsize, AtomicU16, AtomicU32,
+            AtomicU64, AtomicU8, AtomicUsize,
+        },
+    },
+};
+
+/// Reverse of [`SafeBytes`]. Reads value back from its bytes representation.
+///
+/// # Safety
+///
+/// Any bytes of `size_of::<Self>()` length must be valid representation of the type,
+/// including sentinel bytes in place of padding.
+/// Types with restricted validity, like `bool`, references or enums,
+/// must not implement this trait, nor structs with fields of such types.
+///
+/// [`SafeBytes`]: ./trait.SafeBytes.html
+pub unsafe trait FromSafeBytes: PaddingBane + Sized {
+    /// Reads value from bytes.
+    /// Returns [`SafeBytesError::SizeMismatch`] if length of `bytes`
+    /// is not equal to the size of the type.
+    ///
+    /// [`SafeBytesError::SizeMismatch`]: ./enum.SafeBytesError.html#variant.SizeMismatch
+    #[inline]
+    fn from_safe_bytes(bytes: &[u8]) -> Result<Self, SafeBytesError> {
+        if bytes.len() == size_of::<Self>() {
+            // Length is checked above and any bytes are valid value.
+            Ok(unsafe { read_unaligned(bytes.as_ptr() as *const Self) })
+        } else {
+            Err(SafeBytesError::SizeMismatch)
+        }
+    }
+}
+
+/// Checks that padding between fields of the type is initialized by [`SafeBytes`].
+/// Intended for tests of manual [`PaddingBane`] implementations and derived layouts.
+///
+/// Creates two values from bytes filled with `0x00` and `0xff`,
+/// so padding starts with different garbage in each,
+/// and asserts that bytes outside of the fields are equal after [`SafeBytes::safe_bytes`].
+/// Type must implement [`FromSafeBytes`], so that any poison bytes are valid value.
+/// Types that don't report fields with [`PaddingBane::field`] are checked only for length.
+/// Running under Miri also reports any padding byte left uninitialized.
+///
+/// # Panics
+///
+/// Panics naming the type and offset of the first padding byte that wasn't initialized.
+///
+/// ```
+/// # use safe_bytes::{assert_safe_bytes_layout, FromSafeBytes, SafeBytes};
+/// #[derive(SafeBytes)]
+/// #[repr(C)]
+/// struct Header {
+///     kind: u8,
+///     len: u32,
+/// }
+///
+/// unsafe impl FromSafeBytes for Header {}
+///
+/// assert_safe_bytes_layout!(Header);
+/// ```
+///
+/// [`SafeBytes`]: ./trait.SafeBytes.html
+/// [`SafeBytes::safe_bytes`]: ./trait.SafeBytes.html#tymethod.safe_bytes
+/// [`PaddingBane`]: ./trait.PaddingBane.html
+/// [`PaddingBane::field`]: ./trait.PaddingBane.html#method.field
+/// [`FromSafeBytes`]: ./trait.FromSafeBytes.html
+#[macro_export]
+macro_rules! assert_safe_bytes_layout {
+    ($type:ty) => {
+        $crate::__assert_safe_bytes_layout::<$type>(stringify!($type))
+    };
+}
+
+#[doc(hidden)]
+pub fn __assert_safe_bytes_layout<T>(name: &str)
+where
+    T: FromSafeBytes,
+{
+    let mut zeros = poisoned::<T>(0x00);
+    let mut ones = poisoned::<T>(0xff);
+    let fields = zeros.get_fields();
+    let zeros = zeros.safe_bytes();
+    let ones = ones.safe_bytes();
+    assert_eq!(
+        zeros.len(),
+        size_of::<T>(),
+        "`{}` bytes are truncated",
+        name
+    );
+
+    let mut fields = (0..)
+        .map(|index| T::field(fields, index))
+        .take_while(Option::is_some)
+        .flatten()
+        .peekable();
+
+    // Without fields data can't be told apart from padding.
+    if fields.peek().is_none() {
+        return;
+    }
+
+    for offset in 0..size_of::<T>() {
+        let in_field = fields
+            .clone()
+            .any(|field| field.offset() <= offset && offset < field.end());
+
+        assert!(
+            in_field || zeros[offset] == ones[offset],
+            "padding byte {} of `{}` is not initialized",
+            offset,
+            name,
+        );
+    }
+}
+
+/// Returns value with all bytes set to `byte`.
+fn poisoned<T>(byte: u8) -> T
+where
+    T: FromSafeBytes,
+{
+    let mut value = MaybeUninit::<T>::uninit();
+    unsafe {
+        // Any bytes are valid value of `T`.
+        value.as_mut_ptr().write_bytes(byte, 1);
+        value.assume_init()
+    }
+}
+
+macro_rules! impl_from_safe_bytes {
+    ($(<$($g:tt),+>)? for $t:ty) => {
+        unsafe impl $(<$($g),+>)? FromSafeBytes for $t {}
+    };
+}
+
+impl_from_safe_bytes!(for ());
+impl_from_safe_bytes!(for u8);
+impl_from_safe_bytes!(for i8);
+impl_from_safe_bytes!(for u16);
+impl_from_safe_bytes!(for i16);
+impl_from_safe_bytes!(for u32);
+impl_from_safe_bytes!(for i32);
+impl_from_safe_bytes!(for u64);
+impl_from_safe_bytes!(for i64);
+impl_from_safe_bytes!(for usize);
+impl_from_safe_bytes!(for isize);
+impl_from_safe_bytes!(for u128);
+impl_from_safe_bytes!(for i128);
+impl_from_safe_bytes!(for f32);
+impl_from_safe_bytes!(for f64);
+
+impl_from_safe_bytes!(for AtomicU8);
+impl_from_safe_bytes!(for AtomicI8);
+impl_from_safe_bytes!(for AtomicU16);
+impl_from_safe_bytes!(for AtomicI16);
+impl_from_safe_bytes!(for AtomicU32);
+impl_from_safe_bytes!(for AtomicI32);
+impl_from_safe_bytes!(for AtomicU64);
+impl_from_safe_bytes!(for AtomicI64);
+impl_from_safe_bytes!(for AtomicUsize);
+impl_from_safe_bytes!(for AtomicIsize);
+
+impl_from_safe_bytes!(for Option<NonZeroI8>);
+impl_from_safe_bytes!(for Option<NonZeroI16>);
+impl_from_safe_bytes!(for Option<NonZeroI32>);
+impl_from_safe_bytes!(for Option<NonZeroI64>);
+impl_from_safe_bytes!(for Option<NonZeroI128>);
+impl_from_safe_bytes!(for Option<NonZeroIsize>);
+impl_from_safe_bytes!(for Option<NonZeroU8>);
+impl_from_safe_bytes!(for Option<NonZeroU16>);
+impl_from_safe_bytes!(for Option<NonZeroU32>);
+impl_from_safe_bytes!(for Option<NonZeroU64>);
+impl_from_safe_bytes!(for Option<NonZeroU128>);
+impl_from_safe_bytes!(for Option<NonZeroUsize>);
+
+impl_from_safe_bytes!(<T> for PhantomData<T>);
+impl_from_safe_bytes!(for PhantomPinned);
+impl_from_safe_bytes!(<T> for MaybeUninit<T>);
+
+unsafe impl<T, const N: usize> FromSafeBytes for [T; N] where T: FromSafeBytes {}
+unsafe impl<T> FromSafeBytes for ManuallyDrop<T> where T: FromSafeBytes {}
+unsafe impl<T> FromSafeBytes for Wrapping<T> where T: FromSafeBytes {}
+#[cfg(safe_bytes_saturating)]
+unsafe impl<T> FromSafeBytes for core::num::Saturating<T> where T: FromSafeBytes {}
+unsafe impl<T> FromSafeBytes for Reverse<T> where T: FromSafeBytes {}
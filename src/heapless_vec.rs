@@ -0,0 +1,107 @@
+use {
+    crate::{__element_bytes, fill_sentinel, PaddingBane, StaticFields},
+    core::{
+        marker::PhantomData,
+        mem::{size_of, MaybeUninit},
+        ptr::read_unaligned,
+    },
+    heapless::{LenType, Vec},
+};
+
+/// `heapless::Vec` stores length and buffer of possibly uninitialized elements
+/// in private fields.
+/// Buffer is located with public API, but length can't be told apart
+/// from padding around it.
+/// So the impl requires that length and buffer cover the whole vector,
+/// which is checked at compile time.
+struct Layout<T, LenT, const N: usize>(PhantomData<(T, LenT)>);
+
+impl<T, LenT, const N: usize> Layout<T, LenT, N>
+where
+    LenT: LenType,
+{
+    const NO_PADDING: () = assert!(
+        size_of::<Vec<T, N, LenT>>() == size_of::<LenT>() + N * size_of::<T>(),
+        "`heapless::Vec` must not have padding outside of the buffer"
+    );
+}
+
+/// Elements past the length are treated as padding and filled with sentinel bytes,
+/// so bytes of the unused capacity are lost.
+/// Padding of elements in use is filled as usual.
+///
+/// Fields must be the same for any instance, as vectors of different lengths
+/// may share them, e.g. in arrays.
+/// So length is read from the bytes of each vector,
+/// and element type must implement [`StaticFields`]
+/// to get element fields even when there is no element to take them from.
+///
+/// Doesn't compile if the vector has padding outside of the buffer,
+/// e.g. `Vec<u8, 3>` with `usize` length.
+/// Pick capacity or length type so that the buffer is multiple of length alignment.
+///
+/// [`StaticFields`]: ./trait.StaticFields.html
+unsafe impl<T, LenT, const N: usize> PaddingBane for Vec<T, N, LenT>
+where
+    T: StaticFields,
+    LenT: LenType,
+{
+    /// Buffer offset and fields of the elements.
+    type Fields = (usize, T::Fields);
+
+    /// Length and buffer cover the whole vector, so there are no gaps between them.
+    /// Unused capacity depends on the length and is not listed.
+    const PADDING_MAP: &'static [(usize, usize)] = &[];
+
+    #[inline]
+    fn get_fields(&self) -> Self::Fields {
+        let () = Layout::<T, LenT, N>::NO_PADDING;
+        let offset = self.as_ptr() as usize - self as *const Self as usize;
+        (offset, T::fields())
+    }
+
+    #[inline]
+    unsafe fn init_padding(fields: Self::Fields, bytes: &mut [MaybeUninit<u8>]) {
+        Self::init_padding_counted(fields, bytes);
+    }
+
+    #[inline]
+    unsafe fn init_padding_counted(
+        (offset, element): Self::Fields,
+        bytes: &mut [MaybeUninit<u8>],
+    ) -> usize {
+        // Length and buffer cover the whole vector,
+        // so length is either before or after the buffer.
+        let len_offset = if offset == 0 { N * size_of::<T>() } else { 0 };
+        let len = &bytes[len_offset..len_offset + size_of::<LenT>()];
+        let len: usize = read_unaligned(len.as_ptr() as *const LenT)
+            .try_into()
+            .unwrap();
+        // Vector not yet built may hold sentinel bytes,
+        // see `StaticFields::safe_bytes_partial`.
+        let len = len.min(N);
+
+        let buffer = &mut bytes[offset..offset + N * size_of::<T>()];
+        let (used, unused) = buffer.split_at_mut(len * size_of::<T>());
+
+        let mut count = 0;
+        for i in 0..len {
+            count += T::init_padding_counted(element, __element_bytes::<T>(used, i));
+        }
+
+        fill_sentinel(unused);
+        count + unused.len()
+    }
+}
+
+unsafe impl<T, LenT, const N: usize> StaticFields for Vec<T, N, LenT>
+where
+    T: StaticFields,
+    LenT: LenType,
+{
+    #[inline]
+    fn fields() -> Self::Fields {
+        // Layout doesn't depend on the value, so empty vector would do.
+        Self::new().get_fields()
+    }
+}
@@ -18,24 +18,223 @@
 //! And returns shareable reference `&[u8]` because not all
 //! bitpatterns may be allowed for the type.
 //!
+//! # Provided implementations
+//!
+//! [`PaddingBane`], and therefore [`SafeBytes`], is implemented for
+//! primitive numbers, `bool`, `char`, `()`, atomic booleans, integers and pointers,
+//! non-zero integers, raw pointers, `NonNull`, including slice pointers,
+//! function pointers with up to 6 arguments,
+//! `Option` of references, `NonNull`, function pointers, non-zero integers,
+//! `bool` and `char`, `PhantomData`, `PhantomPinned` and `RangeFull`.
+//! Arrays, tuples, `ManuallyDrop`, `Wrapping`, `Saturating` (Rust 1.74 and newer),
+//! `Reverse`, `RangeFrom` and `RangeTo` implement it when element types implement it.
+//! So does `Cell` when element type is also `Copy`,
+//! and `UnsafeCell` when element type implements `StaticFields`.
+//! `MaybeUninit` implements it for any type and is filled with sentinel bytes
+//! entirely, including initialized content.
+//! With `heapless` feature `heapless::Vec` implements it
+//! when element type implements `StaticFields`,
+//! filling unused capacity with sentinel bytes.
+//! [`SafeBytes`] is also implemented for slices of [`PaddingBane`] types.
+//!
+//! # Reading bytes back
+//!
+//! Types for which any bytes are valid value, like numbers and arrays of them,
+//! implement [`FromSafeBytes`] that reads value back from its bytes.
+//! Own structs with such fields may implement it too.
+//! With `serde` feature [`SafeBytesSer`] wrapper serializes value as its safe bytes
+//! and deserializes it back with [`FromSafeBytes`].
+//! With `std` feature [`write_framed`] writes safe bytes to `io::Write`
+//! prefixed with their length, and [`read_framed`] reads value back from such frame.
+//!
+//! # Portable form
+//!
+//! Bytes returned by [`SafeBytes::safe_bytes`] follow target's layout and endianness.
+//! [`PortableBytes`] writes fields in declaration order without padding
+//! and numbers in little-endian byte order instead, producing the same bytes on every target.
+//! Structs implement it with `#[safe_bytes(portable)]` attribute.
+//! `#[safe_bytes(byteorder)]` attribute keeps target's layout instead,
+//! but generates methods writing numeric fields in chosen byte order.
+//!
+//! With `alloc` feature [`debug_bytes`] renders safe bytes as hex dump
+//! split into fields and padding, which helps to debug layout issues.
+//!
+//! # Extending
+//!
+//! Own types should derive [`SafeBytes`] or implement [`PaddingBane`] manually.
+//! Types that can't implement [`PaddingBane`] may implement [`SafeBytes`] directly.
+//! Blanket implementation doesn't conflict with such impls,
+//! as long as the type doesn't implement [`PaddingBane`].
+//! Orphan rules still forbid implementing either trait for foreign types
+//! and for slices of local types, wrap them into local newtype instead.
+//!
+//! # Unsupported types
+//!
+//! Some std types can't implement [`PaddingBane`] soundly.
+//!
+//! `RefCell<T>` stores borrow flag alongside the value and its layout is unspecified.
+//! Bytes outside of the value can't be told apart between the flag and padding,
+//! and filling them would corrupt the flag.
+//! Use `RefCell::get_mut` to get bytes of the value instead.
+//!
+//! `OnceCell<T>` stores `Option<T>` whose discriminant may be stored in niche of `T`.
+//! Empty cell leaves bytes of `T` uninitialized, so they would have to be filled,
+//! but overwriting them with sentinel bytes may turn the discriminant into `Some`
+//! with garbage value inside. Serialize `OnceCell::get` result instead.
+//!
+//! `RangeInclusive<T>` stores private `bool` flag set when iteration is exhausted.
+//! Offsets of `start` and `end` are known from their references,
+//! but the flag can't be located among the remaining bytes,
+//! and filling it with sentinel byte would create invalid `bool`.
+//! Store `start()`, `end()` and `is_empty()` in own `#[repr(C)]` struct instead.
+//!
+//! Atomics of `loom` model checker store a handle to checker's state
+//! instead of the value, and their layout is private.
+//! Their bytes wouldn't represent the value even if padding was known.
+//! Load values into own struct of plain integers to get their bytes.
+//!
+//! `Instant`, `SystemTime` and `Duration` have platform-dependent private fields
+//! and no layout guarantees. Convert them into `Duration` since a known moment
+//! and serialize its seconds and nanoseconds in own `#[repr(C)]` struct instead.
+//! See `examples/system_time.rs`.
+//!
+//! Pointers to trait objects, like `*const dyn Trait`, are not supported.
+//! Vtable of the same type may be duplicated between codegen units,
+//! so bytes of pointers to the same object may differ.
+//! Slice pointers implement [`PaddingBane`], but `Option` of them doesn't,
+//! as `None` leaves the length uninitialized.
+//!
 //! [`SafeBytes`]: ./trait.SafeBytes.html
 //! [`SafeBytes::safe_bytes`]: ./trait.SafeBytes.html#tymethod.safe_bytes
+//! [`PaddingBane`]: ./trait.PaddingBane.html
+//! [`FromSafeBytes`]: ./trait.FromSafeBytes.html
+//! [`SafeBytesSer`]: ./struct.SafeBytesSer.html
+//! [`PortableBytes`]: ./trait.PortableBytes.html
+//! [`debug_bytes`]: ./fn.debug_bytes.html
+//! [`write_framed`]: ./fn.write_framed.html
+//! [`read_framed`]: ./fn.read_framed.html
 
 #![no_std]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+#[cfg(feature = "std")]
+extern crate std;
+
+mod cell;
+mod error;
+mod from_bytes;
 mod pod;
+mod portable;
+mod range;
+mod tuple;
+
+#[cfg(feature = "alloc")]
+mod debug;
+
+#[cfg(feature = "std")]
+mod frame;
+
+#[cfg(feature = "heapless")]
+mod heapless_vec;
+
+#[cfg(feature = "serde")]
+mod ser;
+
+#[cfg(feature = "bitfield")]
+pub mod bitfield;
 
 use core::{
+    cell::{Cell, UnsafeCell},
+    cmp::Reverse,
+    fmt,
+    hash::Hasher,
     mem::{size_of, size_of_val, ManuallyDrop, MaybeUninit},
     num::Wrapping,
+    ptr::{copy_nonoverlapping, write_bytes},
     slice::{from_raw_parts, from_raw_parts_mut},
 };
 
-pub use safe_bytes_derive::SafeBytes;
+#[cfg(safe_bytes_saturating)]
+use core::num::Saturating;
+
+pub use {
+    crate::{
+        cell::SafeBytesCell,
+        error::SafeBytesError,
+        from_bytes::{__assert_safe_bytes_layout, FromSafeBytes},
+        portable::PortableBytes,
+    },
+    safe_bytes_derive::SafeBytes,
+};
+
+#[cfg(feature = "alloc")]
+pub use crate::debug::debug_bytes;
+
+#[cfg(feature = "std")]
+pub use crate::frame::{read_framed, write_framed};
+
+#[cfg(feature = "serde")]
+pub use crate::ser::SafeBytesSer;
 
 #[doc(hidden)]
 pub use core;
 
+/// Returns bytes of the value of a type without padding.
+/// Unlike [`SafeBytes::safe_bytes`] it can be called in `const` context,
+/// e.g. to compute bytes of constant struct at compile time.
+///
+/// Doesn't compile for types with [`PaddingBane::NEEDS_PADDING`] set to `true`,
+/// as their padding can't be initialized in `const` context.
+/// Requires Rust 1.83 or newer.
+///
+/// ```
+/// # use safe_bytes::{safe_bytes_const, SafeBytes};
+/// #[derive(SafeBytes)]
+/// #[repr(C)]
+/// struct Header {
+///     kind: u16,
+///     flags: u16,
+/// }
+///
+/// const BYTES: [u8; 4] = {
+///     let mut header = Header { kind: 1, flags: 1 };
+///     let mut bytes = [0; 4];
+///     bytes.copy_from_slice(safe_bytes_const(&mut header));
+///     bytes
+/// };
+///
+/// assert_eq!(BYTES, [1u16.to_ne_bytes(), 1u16.to_ne_bytes()].concat()[..]);
+/// ```
+///
+/// [`SafeBytes::safe_bytes`]: ./trait.SafeBytes.html#tymethod.safe_bytes
+/// [`PaddingBane::NEEDS_PADDING`]: ./trait.PaddingBane.html#associatedconstant.NEEDS_PADDING
+#[cfg(safe_bytes_const_mut)]
+#[inline(always)]
+pub const fn safe_bytes_const<T>(value: &mut T) -> &[u8]
+where
+    T: PaddingBane,
+{
+    let () = AssertDense::<T>::DENSE;
+    // All bytes of the value are initialized, as it has no padding.
+    unsafe { from_raw_parts(value as *mut T as *const u8, size_of::<T>()) }
+}
+
+#[cfg(safe_bytes_const_mut)]
+struct AssertDense<T>(core::marker::PhantomData<T>);
+
+#[cfg(safe_bytes_const_mut)]
+impl<T> AssertDense<T>
+where
+    T: PaddingBane,
+{
+    const DENSE: () = assert!(
+        !T::NEEDS_PADDING,
+        "type has padding, use `SafeBytes::safe_bytes` instead"
+    );
+}
+
 /// Creates [`TypeField`] for fieled of the given instance.
 /// Can be used to implement [`PaddingBane::get_fields`].
 ///
@@ -55,16 +254,208 @@ macro_rules! typed_field {
         let field_offset = field_address.checked_sub(base_address).unwrap();
         let field_sub = $crate::PaddingBane::get_fields(field_reference);
 
-        TypedField {
-            raw: $crate::Field {
-                offset: field_offset,
-                size: field_size,
-            },
-            sub: field_sub,
+        $crate::TypedField::new($crate::Field::new(field_offset, field_size), field_sub)
+    }};
+}
+
+#[doc(hidden)]
+#[cfg(safe_bytes_offset_of)]
+#[macro_export]
+macro_rules! __offset_of {
+    ($type:ty, $field:tt) => {
+        $crate::core::mem::offset_of!($type, $field)
+    };
+}
+
+/// Fallback for compilers without `core::mem::offset_of!`.
+/// Usable in const context as well.
+#[doc(hidden)]
+#[cfg(not(safe_bytes_offset_of))]
+#[macro_export]
+macro_rules! __offset_of {
+    ($type:ty, $field:tt) => {{
+        let uninit = $crate::core::mem::MaybeUninit::<$type>::uninit();
+        let base = uninit.as_ptr();
+        #[allow(unused_unsafe)]
+        unsafe {
+            let field = $crate::core::ptr::addr_of!((*base).$field);
+            (field as *const u8).offset_from(base as *const u8) as usize
         }
     }};
 }
 
+/// Implements [`PaddingBane`] for struct with public fields
+/// given field names and types.
+///
+/// This is useful for types that can't derive [`SafeBytes`],
+/// e.g. generated by `bindgen`.
+/// Orphan rules still apply, so the type must be defined in the same crate.
+/// All fields must be listed and be accessible at the invocation site,
+/// private fields are reported as errors pointing at their names.
+///
+/// ```
+/// # use safe_bytes::{impl_padding_bane_struct, SafeBytes};
+/// mod generated {
+///     #[repr(C)]
+///     pub struct Header {
+///         pub kind: u8,
+///         pub len: u32,
+///     }
+/// }
+///
+/// impl_padding_bane_struct!(generated::Header { kind: u8, len: u32 });
+///
+/// let mut header = generated::Header { kind: 1, len: 2 };
+/// assert_eq!(header.safe_bytes()[1..4], [0xfe; 3]);
+/// ```
+///
+/// [`PaddingBane`]: ./trait.PaddingBane.html
+/// [`SafeBytes`]: ./trait.SafeBytes.html
+#[macro_export]
+macro_rules! impl_padding_bane_struct {
+    ($type:path { $($field:ident : $field_type:ty),* $(,)? }) => {
+        unsafe impl $crate::PaddingBane for $type {
+            type Fields = ($($crate::TypedField<$field_type>,)*);
+
+            const PADDING_MAP: &'static [(usize, usize)] = {
+                let gaps: &'static (_, usize) = &<Self as $crate::__PaddingGaps<
+                    { [$(stringify!($field)),*].len() + 1 },
+                >>::GAPS;
+                gaps.0.split_at(gaps.1).0
+            };
+
+            const NEEDS_PADDING: bool = !<Self as $crate::PaddingBane>::PADDING_MAP.is_empty()
+                $(|| <$field_type as $crate::PaddingBane>::NEEDS_PADDING)*;
+
+            #[inline(always)]
+            fn get_fields(&self) -> Self::Fields {
+                // Ensure that all fields are listed.
+                let $type { $($field: _,)* } = self;
+
+                ($($crate::typed_field!(*self, $type, $field),)*)
+            }
+
+            #[inline]
+            unsafe fn init_padding(fields: Self::Fields, bytes: &mut [$crate::core::mem::MaybeUninit<u8>]) {
+                Self::init_padding_counted(fields, bytes);
+            }
+
+            #[inline]
+            unsafe fn init_padding_counted(fields: Self::Fields, bytes: &mut [$crate::core::mem::MaybeUninit<u8>]) -> usize {
+                let ($($field,)*) = fields;
+                #[allow(unused_mut)]
+                let mut count = $crate::__fill_gaps(&mut [$($field.raw(),)*], bytes);
+                $(
+                    let field_bytes = &mut bytes[$field.raw().offset()..$field.raw().end()];
+                    count += <$field_type as $crate::PaddingBane>::init_padding_counted($field.sub(), field_bytes);
+                )*
+                count
+            }
+        }
+
+        // Higher-ranked bounds are checked only when the impl is used.
+        unsafe impl $crate::StaticFields for $type
+        where
+            $(for<'__safe_bytes> $field_type: $crate::StaticFields,)*
+        {
+            #[inline(always)]
+            fn fields() -> Self::Fields {
+                ($($crate::TypedField::new(
+                    $crate::Field::new(
+                        $crate::__offset_of!(Self, $field),
+                        $crate::core::mem::size_of::<$field_type>(),
+                    ),
+                    <$field_type as $crate::StaticFields>::fields(),
+                ),)*)
+            }
+        }
+
+        impl $crate::__PaddingGaps<{ [$(stringify!($field)),*].len() + 1 }> for $type {
+            const GAPS: ([(usize, usize); [$(stringify!($field)),*].len() + 1], usize) =
+                $crate::__padding_gaps(
+                    [$($crate::Field::new(
+                        $crate::__offset_of!($type, $field),
+                        $crate::core::mem::size_of::<$field_type>(),
+                    ),)*],
+                    $crate::core::mem::size_of::<$type>(),
+                );
+        }
+    };
+}
+
+/// Implements [`PaddingBane`] for a newtype by delegating to its only field,
+/// like provided implementations for `Wrapping` and `ManuallyDrop` do.
+/// Takes type name with optional generic parameters and the field with its type.
+///
+/// Wrapper must be of the same size as the field, e.g. `#[repr(transparent)]`,
+/// so that the field covers all bytes of the wrapper.
+/// This is checked at compile time.
+///
+/// ```
+/// use safe_bytes::{delegate_padding_bane, SafeBytes};
+///
+/// #[repr(transparent)]
+/// pub struct Id<T>(T);
+///
+/// delegate_padding_bane!(Id<T> { 0: T });
+///
+/// assert_eq!(Id(1u16).safe_bytes(), 1u16.to_ne_bytes());
+/// ```
+///
+/// [`PaddingBane`]: ./trait.PaddingBane.html
+#[macro_export]
+macro_rules! delegate_padding_bane {
+    ($type:ident $(<$($g:ident),+ $(,)?>)? { $field:tt : $inner:ty $(,)? }) => {
+        impl $(<$($g),+>)? $type $(<$($g),+>)? {
+            #[doc(hidden)]
+            const __SAFE_BYTES_DELEGATE: () = $crate::core::assert!(
+                $crate::core::mem::size_of::<Self>() == $crate::core::mem::size_of::<$inner>(),
+                "wrapper must be of the same size as the field",
+            );
+        }
+
+        unsafe impl $(<$($g),+>)? $crate::PaddingBane for $type $(<$($g),+>)?
+        where
+            $inner: $crate::PaddingBane,
+        {
+            type Fields = <$inner as $crate::PaddingBane>::Fields;
+
+            const PADDING_MAP: &'static [(usize, usize)] =
+                <$inner as $crate::PaddingBane>::PADDING_MAP;
+
+            const NEEDS_PADDING: bool = <$inner as $crate::PaddingBane>::NEEDS_PADDING;
+
+            #[inline(always)]
+            fn get_fields(&self) -> Self::Fields {
+                let () = Self::__SAFE_BYTES_DELEGATE;
+                $crate::PaddingBane::get_fields(&self.$field)
+            }
+
+            #[inline(always)]
+            unsafe fn init_padding(fields: Self::Fields, bytes: &mut [$crate::core::mem::MaybeUninit<u8>]) {
+                <$inner as $crate::PaddingBane>::init_padding(fields, bytes);
+            }
+
+            #[inline(always)]
+            unsafe fn init_padding_counted(fields: Self::Fields, bytes: &mut [$crate::core::mem::MaybeUninit<u8>]) -> usize {
+                <$inner as $crate::PaddingBane>::init_padding_counted(fields, bytes)
+            }
+        }
+
+        // Higher-ranked bound is checked only when the impl is used.
+        unsafe impl $(<$($g),+>)? $crate::StaticFields for $type $(<$($g),+>)?
+        where
+            for<'__safe_bytes> $inner: $crate::StaticFields,
+        {
+            #[inline(always)]
+            fn fields() -> Self::Fields {
+                let () = Self::__SAFE_BYTES_DELEGATE;
+                <$inner as $crate::StaticFields>::fields()
+            }
+        }
+    };
+}
+
 /// Trait for types that can initialize their padding in
 /// their bytes representation.
 ///
@@ -78,6 +469,11 @@ macro_rules! typed_field {
 /// Or implement [`PaddingBane`] instead if deriving is not possible,
 /// as [`SafeBytes`] has blanket implementation for [`PaddingBane`] implementors.
 ///
+/// There is no implementation for `&mut T`, as it would overlap
+/// with the blanket one. Method calls on references autoderef to the referent,
+/// and generic code can reborrow with `&mut **reference`.
+/// Bytes of the referent are returned, never the reference's address.
+///
 /// [`SafeBytes`]: ./trait.SafeBytes.html
 /// [`PaddingBane`]: ./trait.PaddingBane.html
 pub trait SafeBytes {
@@ -87,10 +483,42 @@ pub trait SafeBytes {
 }
 
 /// This trait must be implemented in order to fill padding bytes of an object.
+///
+/// # Safety
+///
+/// Implementation must initialize all bytes of the value
+/// that may be uninitialized, i.e. padding bytes,
+/// when [`PaddingBane::init_padding`] is called.
+///
+/// [`PaddingBane::init_padding`]: ./trait.PaddingBane.html#tymethod.init_padding
 pub unsafe trait PaddingBane {
     /// Metadata about type's fields.
     type Fields: Copy;
 
+    /// Padding gaps between the fields and after the last field
+    /// as `(offset, len)` pairs sorted by offset.
+    /// Padding inside the fields is described by fields' own maps.
+    ///
+    /// Default value is empty.
+    /// Derived and provided implementations override it.
+    const PADDING_MAP: &'static [(usize, usize)] = &[];
+
+    /// Whether [`PaddingBane::init_padding`] has any bytes to initialize.
+    /// When `false`, callers skip getting fields and calling it,
+    /// e.g. for each element of a slice.
+    ///
+    /// Default value is `true`.
+    /// Derived and provided implementations set it to `false`
+    /// for types without padding, like primitives and dense structs of them.
+    ///
+    /// # Safety
+    ///
+    /// Must be `true` unless all bytes of any value of the type are always initialized
+    /// and [`PaddingBane::init_padding`] doesn't write anything.
+    ///
+    /// [`PaddingBane::init_padding`]: ./trait.PaddingBane.html#tymethod.init_padding
+    const NEEDS_PADDING: bool = true;
+
     /// Return fields metadata.
     ///
     /// # Safety
@@ -100,16 +528,247 @@ pub unsafe trait PaddingBane {
     /// fetch field offsets.
     fn get_fields(&self) -> Self::Fields;
 
+    /// Returns offset and size of the field with given index in declaration order.
+    /// Returns `None` if there is no such field.
+    ///
+    /// Default implementation returns `None`.
+    /// Derived implementations and tuples override it.
+    #[inline(always)]
+    fn field(fields: Self::Fields, index: usize) -> Option<Field> {
+        let _ = (fields, index);
+        None
+    }
+
+    /// Returns bytes representation of the value from the beginning
+    /// to the end of the field with given index in declaration order.
+    /// Useful to send header fields of the struct first.
+    ///
+    /// Padding bytes of the whole value are initialized.
+    ///
+    /// # Panics
+    ///
+    /// Panics if [`PaddingBane::field`] returns `None` for the index.
+    ///
+    /// [`PaddingBane::field`]: ./trait.PaddingBane.html#method.field
+    #[inline]
+    fn safe_bytes_prefix(&mut self, up_to_field: usize) -> &[u8]
+    where
+        Self: Sized,
+    {
+        let field = Self::field(self.get_fields(), up_to_field).expect("no field with such index");
+        &self.safe_bytes()[..field.end()]
+    }
+
+    /// Same as [`SafeBytes::safe_bytes`] but returns mutable bytes.
+    /// Useful to patch serialized bytes in place, e.g. checksum fields.
+    ///
+    /// # Safety
+    ///
+    /// Writes through returned slice must leave the value valid.
+    /// That is, they must not create invalid bit patterns
+    /// for fields with restricted validity, like `bool`, `NonNull` or enums.
+    ///
+    /// [`SafeBytes::safe_bytes`]: ./trait.SafeBytes.html#tymethod.safe_bytes
+    #[inline]
+    unsafe fn as_safe_bytes_mut(&mut self) -> &mut [u8]
+    where
+        Self: Sized,
+    {
+        let fields = self.get_fields();
+        let bytes = maybe_init_bytes_of(self);
+        Self::init_padding(fields, bytes);
+        assume_slice_init_mut(bytes)
+    }
+
+    /// Returns bytes representation of the value with bytes of the field
+    /// with given index in declaration order set to zero.
+    /// Useful to compute checksum of the value excluding checksum field.
+    ///
+    /// Padding bytes of the whole value are initialized.
+    /// The field is zeroed in the value itself and stays zero afterwards,
+    /// restore it when checksum is computed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if [`PaddingBane::field`] returns `None` for the index.
+    ///
+    /// # Safety
+    ///
+    /// All zero bytes must be valid bit pattern for the field type.
+    /// It is not for references, `NonNull`, non-zero integers
+    /// and many enums.
+    ///
+    /// [`PaddingBane::field`]: ./trait.PaddingBane.html#method.field
+    #[inline]
+    unsafe fn safe_bytes_with_field_zeroed(&mut self, field_index: usize) -> &[u8]
+    where
+        Self: Sized,
+    {
+        let field = Self::field(self.get_fields(), field_index).expect("no field with such index");
+        let bytes = self.as_safe_bytes_mut();
+        bytes[field.offset()..field.end()].fill(0);
+        bytes
+    }
+
+    /// Initializes padding bytes and copies bytes representation
+    /// of the value into possibly uninitialized `out` buffer.
+    /// Returns initialized `out`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if length of `out` is not equal to the size of the value.
+    #[inline]
+    fn safe_bytes_into_uninit<'a>(&mut self, out: &'a mut [MaybeUninit<u8>]) -> &'a [u8]
+    where
+        Self: Sized,
+    {
+        assert_eq!(
+            out.len(),
+            size_of::<Self>(),
+            "output length must be equal to the size of the value"
+        );
+        let bytes = self.safe_bytes();
+        unsafe {
+            // Lengths are checked above, and `out` is a separate buffer.
+            copy_nonoverlapping(bytes.as_ptr(), out.as_mut_ptr() as *mut u8, bytes.len());
+            assume_slice_init(out)
+        }
+    }
+
+    /// Feeds bytes representation of the value into the hasher.
+    /// Padding is initialized with the same bytes every time,
+    /// so values with equal bytes produce equal hashes
+    /// regardless of what their padding contained before.
+    /// Note that equal values may still have different bytes, e.g. `0.0` and `-0.0`.
+    #[inline]
+    fn hash_safe_bytes<H: Hasher>(&mut self, state: &mut H)
+    where
+        Self: Sized,
+    {
+        state.write(self.safe_bytes());
+    }
+
+    /// Returns `true` if bytes representation of the value is equal to `expected`.
+    /// Returns `false` if lengths differ.
+    /// Useful to check that value serializes to a known layout.
+    #[inline]
+    fn safe_bytes_matches(&mut self, expected: &[u8]) -> bool
+    where
+        Self: Sized,
+    {
+        self.safe_bytes() == expected
+    }
+
     /// Fills padding bytes in the bytes array.
     /// Padding bytes are bytes where no fields of the struct are stored
     /// or padding bytes of the fields.
     ///
     /// # Safety
     ///
-    /// `fields` must be created from any instance of `Self`.
+    /// `fields` must be created from any instance of `Self`
+    /// or returned by [`StaticFields::fields`].
     /// `bytes` must be created by casting `&mut Self` or, for a field,
     /// it must be subslice of the parent's bytes where field is stored.
+    ///
+    /// [`StaticFields::fields`]: ./trait.StaticFields.html#tymethod.fields
     unsafe fn init_padding(fields: Self::Fields, bytes: &mut [MaybeUninit<u8>]);
+
+    /// Same as [`PaddingBane::init_padding`],
+    /// but returns number of padding bytes filled.
+    /// Useful to check padding overhead of the layout.
+    ///
+    /// Default implementation returns 0.
+    /// Derived and provided implementations override it.
+    ///
+    /// # Safety
+    ///
+    /// Same as for [`PaddingBane::init_padding`].
+    ///
+    /// [`PaddingBane::init_padding`]: ./trait.PaddingBane.html#tymethod.init_padding
+    #[inline(always)]
+    unsafe fn init_padding_counted(fields: Self::Fields, bytes: &mut [MaybeUninit<u8>]) -> usize {
+        Self::init_padding(fields, bytes);
+        0
+    }
+}
+
+/// [`PaddingBane`] implementation that builds fields without an instance.
+/// Allows getting fields without an instance, e.g. to inspect layout in generic code.
+///
+/// Fields of any [`PaddingBane`] type are the same for every instance,
+/// this trait only tells that no instance is needed to get them.
+/// Implemented for primitives, derived structs with such fields,
+/// arrays, tuples and wrappers of such types,
+/// and `heapless::Vec` of such elements.
+/// Not available where fields can't be built without an instance,
+/// e.g. for manual implementations that take them from the value.
+///
+/// # Safety
+///
+/// [`StaticFields::fields`] must return the same value
+/// as [`PaddingBane::get_fields`] returns for any instance of the type.
+///
+/// [`PaddingBane`]: ./trait.PaddingBane.html
+/// [`PaddingBane::get_fields`]: ./trait.PaddingBane.html#tymethod.get_fields
+/// [`StaticFields::fields`]: ./trait.StaticFields.html#tymethod.fields
+pub unsafe trait StaticFields: PaddingBane {
+    /// Returns fields of any instance of the type.
+    fn fields() -> Self::Fields;
+
+    /// Returns bytes representation of partially built value,
+    /// where only fields before `initialized_fields` index in declaration order
+    /// are initialized. Useful when value is parsed field by field.
+    ///
+    /// Bytes of the remaining fields are filled with sentinel bytes,
+    /// and padding bytes of the whole value are initialized.
+    /// The value stays partially built, but with no uninitialized bytes left.
+    ///
+    /// # Panics
+    ///
+    /// Panics if [`PaddingBane::field`] returns `None` for `initialized_fields - 1`.
+    ///
+    /// # Safety
+    ///
+    /// Fields with index less than `initialized_fields` must be initialized.
+    /// If the type doesn't report its fields, the whole value must be initialized.
+    ///
+    /// ```
+    /// # use {core::{mem::MaybeUninit, ptr::addr_of_mut}, safe_bytes::{SafeBytes, StaticFields}};
+    /// #[derive(SafeBytes)]
+    /// #[repr(C)]
+    /// struct Header {
+    ///     kind: u8,
+    ///     len: u16,
+    /// }
+    ///
+    /// let mut header = MaybeUninit::<Header>::uninit();
+    /// unsafe {
+    ///     addr_of_mut!((*header.as_mut_ptr()).kind).write(1);
+    ///     assert_eq!(Header::safe_bytes_partial(&mut header, 1), &[1, 0xfe, 0xfe, 0xfe]);
+    /// }
+    /// ```
+    ///
+    /// [`PaddingBane::field`]: ./trait.PaddingBane.html#method.field
+    #[inline]
+    unsafe fn safe_bytes_partial(value: &mut MaybeUninit<Self>, initialized_fields: usize) -> &[u8]
+    where
+        Self: Sized,
+    {
+        let fields = Self::fields();
+        if initialized_fields > 0 {
+            Self::field(fields, initialized_fields - 1).expect("no field with such index");
+        }
+
+        let bytes = maybe_init_bytes_of(value);
+        let mut index = initialized_fields;
+        while let Some(field) = Self::field(fields, index) {
+            fill_sentinel(&mut bytes[field.offset()..field.end()]);
+            index += 1;
+        }
+
+        Self::init_padding(fields, bytes);
+        assume_slice_init(bytes)
+    }
 }
 
 impl<T> SafeBytes for T
@@ -118,15 +777,20 @@ where
 {
     #[inline]
     fn safe_bytes(&mut self) -> &[u8] {
-        let fields = self.get_fields();
         unsafe {
-            let bytes = maybe_init_bytes_of(self);
-            Self::init_padding(fields, bytes);
-            assume_slice_init(&*bytes)
+            if T::NEEDS_PADDING {
+                let fields = self.get_fields();
+                T::init_padding(fields, maybe_init_bytes_of(self));
+            }
+            assume_slice_init(maybe_init_bytes_of(self))
         }
     }
 }
 
+/// Bytes of the elements are returned as is,
+/// only padding bytes of each element are initialized.
+/// No validity checks are performed for types with restricted bit patterns,
+/// like `bool`, as their values are valid by construction.
 impl<T> SafeBytes for [T]
 where
     T: PaddingBane,
@@ -135,52 +799,76 @@ where
         if self.is_empty() {
             &[]
         } else {
-            let fields = self[0].get_fields();
-            let len = self.len();
-            unsafe {
-                let bytes = maybe_init_bytes_of(self);
-                for i in 0..len {
-                    let start = i * size_of::<T>();
-                    let end = start + size_of::<T>();
-                    T::init_padding(fields, &mut bytes[start..end]);
+            if T::NEEDS_PADDING {
+                let fields = self[0].get_fields();
+                let len = self.len();
+                unsafe {
+                    let bytes = maybe_init_bytes_of(self);
+                    for i in 0..len {
+                        // `bytes` are exactly `len * size_of::<T>()` long.
+                        T::init_padding(fields, __element_bytes::<T>(bytes, i));
+                    }
                 }
-                assume_slice_init(&*bytes)
             }
+            unsafe { assume_slice_init(maybe_init_bytes_of(self)) }
         }
     }
 }
 
-macro_rules! impl_for_array {
-    ($N:tt) => {
-        unsafe impl<T> PaddingBane for [T; $N]
-        where
-            T: PaddingBane,
-        {
-            type Fields = T::Fields;
-            #[inline(always)]
-            fn get_fields(&self) -> T::Fields {
-                self[0].get_fields()
-            }
+unsafe impl<T, const N: usize> PaddingBane for [T; N]
+where
+    T: PaddingBane,
+{
+    /// Fields of the first element. `None` for empty arrays.
+    ///
+    /// Element fields are taken from an instance, as element type
+    /// is not required to implement [`StaticFields`].
+    /// When it does, `<[T; N]>::fields()` gives the same value without touching elements.
+    ///
+    /// [`StaticFields`]: ./trait.StaticFields.html
+    type Fields = Option<T::Fields>;
 
-            #[inline(always)]
-            unsafe fn init_padding(fields: T::Fields, bytes: &mut [MaybeUninit<u8>]) {
-                for i in 0 .. $N {
-                    let start = i * size_of::<T>();
-                    let end = start + size_of::<T>();
-                    T::init_padding(fields, &mut bytes[start..end]);
-                }
+    const NEEDS_PADDING: bool = N > 0 && T::NEEDS_PADDING;
+
+    #[inline(always)]
+    fn get_fields(&self) -> Self::Fields {
+        self.first().map(T::get_fields)
+    }
+
+    #[inline(always)]
+    unsafe fn init_padding(fields: Self::Fields, bytes: &mut [MaybeUninit<u8>]) {
+        if let Some(fields) = fields {
+            for i in 0..N {
+                // `bytes` are exactly `N * size_of::<T>()` long.
+                T::init_padding(fields, __element_bytes::<T>(bytes, i));
             }
         }
-    };
+    }
 
-    ($($N:tt)*) => {
-        $(impl_for_array!($N);)*
-    };
+    #[inline(always)]
+    unsafe fn init_padding_counted(fields: Self::Fields, bytes: &mut [MaybeUninit<u8>]) -> usize {
+        let mut count = 0;
+        if let Some(fields) = fields {
+            for i in 0..N {
+                count += T::init_padding_counted(fields, __element_bytes::<T>(bytes, i));
+            }
+        }
+        count
+    }
 }
 
-impl_for_array! {
-    1 2 3 4 5 6 7 8 9 10 11 12 13 14 15 16 17 18 19 20 21 22 23 24 25 26 27 28 29 30 31 32
-    48 64 96 128 256 512 1024 2048 4096 8192 16384 32768 65536
+unsafe impl<T, const N: usize> StaticFields for [T; N]
+where
+    T: StaticFields,
+{
+    #[inline(always)]
+    fn fields() -> Self::Fields {
+        if N == 0 {
+            None
+        } else {
+            Some(T::fields())
+        }
+    }
 }
 
 unsafe impl<T> PaddingBane for ManuallyDrop<T>
@@ -189,15 +877,34 @@ where
 {
     type Fields = T::Fields;
 
+    const PADDING_MAP: &'static [(usize, usize)] = T::PADDING_MAP;
+
+    const NEEDS_PADDING: bool = T::NEEDS_PADDING;
+
     #[inline(always)]
     fn get_fields(&self) -> Self::Fields {
-        (&**self).get_fields()
+        (**self).get_fields()
     }
 
     #[inline(always)]
     unsafe fn init_padding(fields: Self::Fields, bytes: &mut [MaybeUninit<u8>]) {
         T::init_padding(fields, bytes);
     }
+
+    #[inline(always)]
+    unsafe fn init_padding_counted(fields: Self::Fields, bytes: &mut [MaybeUninit<u8>]) -> usize {
+        T::init_padding_counted(fields, bytes)
+    }
+}
+
+unsafe impl<T> StaticFields for ManuallyDrop<T>
+where
+    T: StaticFields,
+{
+    #[inline(always)]
+    fn fields() -> Self::Fields {
+        T::fields()
+    }
 }
 
 unsafe impl<T> PaddingBane for Wrapping<T>
@@ -206,6 +913,10 @@ where
 {
     type Fields = T::Fields;
 
+    const PADDING_MAP: &'static [(usize, usize)] = T::PADDING_MAP;
+
+    const NEEDS_PADDING: bool = T::NEEDS_PADDING;
+
     #[inline(always)]
     fn get_fields(&self) -> Self::Fields {
         self.0.get_fields()
@@ -215,25 +926,640 @@ where
     unsafe fn init_padding(fields: Self::Fields, bytes: &mut [MaybeUninit<u8>]) {
         T::init_padding(fields, bytes);
     }
-}
+
+    #[inline(always)]
+    unsafe fn init_padding_counted(fields: Self::Fields, bytes: &mut [MaybeUninit<u8>]) -> usize {
+        T::init_padding_counted(fields, bytes)
+    }
+}
+
+unsafe impl<T> StaticFields for Wrapping<T>
+where
+    T: StaticFields,
+{
+    #[inline(always)]
+    fn fields() -> Self::Fields {
+        T::fields()
+    }
+}
+
+#[cfg(safe_bytes_saturating)]
+unsafe impl<T> PaddingBane for Saturating<T>
+where
+    T: PaddingBane,
+{
+    type Fields = T::Fields;
+
+    const PADDING_MAP: &'static [(usize, usize)] = T::PADDING_MAP;
+
+    const NEEDS_PADDING: bool = T::NEEDS_PADDING;
+
+    #[inline(always)]
+    fn get_fields(&self) -> Self::Fields {
+        self.0.get_fields()
+    }
+
+    #[inline(always)]
+    unsafe fn init_padding(fields: Self::Fields, bytes: &mut [MaybeUninit<u8>]) {
+        T::init_padding(fields, bytes);
+    }
+
+    #[inline(always)]
+    unsafe fn init_padding_counted(fields: Self::Fields, bytes: &mut [MaybeUninit<u8>]) -> usize {
+        T::init_padding_counted(fields, bytes)
+    }
+}
+
+#[cfg(safe_bytes_saturating)]
+unsafe impl<T> StaticFields for Saturating<T>
+where
+    T: StaticFields,
+{
+    #[inline(always)]
+    fn fields() -> Self::Fields {
+        T::fields()
+    }
+}
+
+unsafe impl<T> PaddingBane for Reverse<T>
+where
+    T: PaddingBane,
+{
+    type Fields = T::Fields;
+
+    const PADDING_MAP: &'static [(usize, usize)] = T::PADDING_MAP;
+
+    const NEEDS_PADDING: bool = T::NEEDS_PADDING;
+
+    #[inline(always)]
+    fn get_fields(&self) -> Self::Fields {
+        self.0.get_fields()
+    }
+
+    #[inline(always)]
+    unsafe fn init_padding(fields: Self::Fields, bytes: &mut [MaybeUninit<u8>]) {
+        T::init_padding(fields, bytes);
+    }
+
+    #[inline(always)]
+    unsafe fn init_padding_counted(fields: Self::Fields, bytes: &mut [MaybeUninit<u8>]) -> usize {
+        T::init_padding_counted(fields, bytes)
+    }
+}
+
+unsafe impl<T> StaticFields for Reverse<T>
+where
+    T: StaticFields,
+{
+    #[inline(always)]
+    fn fields() -> Self::Fields {
+        T::fields()
+    }
+}
+
+/// Fields are read from a copy of the value,
+/// so no reference into the cell outlives the read.
+unsafe impl<T> PaddingBane for Cell<T>
+where
+    T: PaddingBane + Copy,
+{
+    type Fields = T::Fields;
+
+    const PADDING_MAP: &'static [(usize, usize)] = T::PADDING_MAP;
+
+    const NEEDS_PADDING: bool = T::NEEDS_PADDING;
+
+    #[inline(always)]
+    fn get_fields(&self) -> Self::Fields {
+        self.get().get_fields()
+    }
+
+    #[inline(always)]
+    unsafe fn init_padding(fields: Self::Fields, bytes: &mut [MaybeUninit<u8>]) {
+        T::init_padding(fields, bytes);
+    }
+
+    #[inline(always)]
+    unsafe fn init_padding_counted(fields: Self::Fields, bytes: &mut [MaybeUninit<u8>]) -> usize {
+        T::init_padding_counted(fields, bytes)
+    }
+}
+
+unsafe impl<T> StaticFields for Cell<T>
+where
+    T: StaticFields + Copy,
+{
+    #[inline(always)]
+    fn fields() -> Self::Fields {
+        T::fields()
+    }
+}
+
+/// `UnsafeCell` can't be read through shared reference,
+/// so layout is taken from the type and doesn't depend on the value.
+unsafe impl<T> PaddingBane for UnsafeCell<T>
+where
+    T: StaticFields,
+{
+    type Fields = T::Fields;
+
+    const PADDING_MAP: &'static [(usize, usize)] = T::PADDING_MAP;
+
+    const NEEDS_PADDING: bool = T::NEEDS_PADDING;
+
+    #[inline(always)]
+    fn get_fields(&self) -> Self::Fields {
+        T::fields()
+    }
+
+    #[inline(always)]
+    unsafe fn init_padding(fields: Self::Fields, bytes: &mut [MaybeUninit<u8>]) {
+        T::init_padding(fields, bytes);
+    }
+
+    #[inline(always)]
+    unsafe fn init_padding_counted(fields: Self::Fields, bytes: &mut [MaybeUninit<u8>]) -> usize {
+        T::init_padding_counted(fields, bytes)
+    }
+}
+
+unsafe impl<T> StaticFields for UnsafeCell<T>
+where
+    T: StaticFields,
+{
+    #[inline(always)]
+    fn fields() -> Self::Fields {
+        T::fields()
+    }
+}
+
+/// The whole value is treated as padding and filled with sentinel bytes,
+/// as initialized bytes can't be told apart from uninitialized ones.
+/// This overwrites initialized content too, so buffers with meaningful bytes
+/// should be stored as initialized arrays instead.
+unsafe impl<T> PaddingBane for MaybeUninit<T> {
+    type Fields = ();
+
+    const PADDING_MAP: &'static [(usize, usize)] = {
+        let gaps: &'static (_, usize) = &<Self as __PaddingGaps<1>>::GAPS;
+        gaps.0.split_at(gaps.1).0
+    };
+
+    #[inline(always)]
+    fn get_fields(&self) -> Self::Fields {}
+
+    #[inline(always)]
+    unsafe fn init_padding(_fields: Self::Fields, bytes: &mut [MaybeUninit<u8>]) {
+        fill_sentinel(bytes);
+    }
+
+    #[inline(always)]
+    unsafe fn init_padding_counted(_fields: Self::Fields, bytes: &mut [MaybeUninit<u8>]) -> usize {
+        fill_sentinel(bytes);
+        bytes.len()
+    }
+}
+
+unsafe impl<T> StaticFields for MaybeUninit<T> {
+    #[inline(always)]
+    fn fields() -> Self::Fields {}
+}
+
+impl<T> __PaddingGaps<1> for MaybeUninit<T> {
+    const GAPS: ([(usize, usize); 1], usize) = __padding_gaps::<0, 1>([], size_of::<T>());
+}
 
 /// Basic field information.
 /// Enough to fill padding bytes between fields.
-#[derive(Clone, Copy)]
+///
+/// Public fields are kept for backward compatibility.
+/// [`Field::new`] and accessor methods are stable
+/// regardless of future changes in the internal representation
+/// and should be preferred in manual [`PaddingBane`] implementations.
+///
+/// [`Field::new`]: ./struct.Field.html#method.new
+/// [`PaddingBane`]: ./trait.PaddingBane.html
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub struct Field {
+    /// Offset of the field in bytes.
     pub offset: usize,
+
+    /// Size of the field in bytes.
     pub size: usize,
 }
 
+impl Field {
+    /// Creates field information from its offset and size in bytes.
+    #[inline(always)]
+    pub const fn new(offset: usize, size: usize) -> Self {
+        Field { offset, size }
+    }
+
+    /// Returns offset of the field in bytes.
+    #[inline(always)]
+    pub const fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// Returns size of the field in bytes.
+    #[inline(always)]
+    pub const fn size(&self) -> usize {
+        self.size
+    }
+
+    /// Returns offset of the first byte after the field.
+    #[inline(always)]
+    pub const fn end(&self) -> usize {
+        self.offset + self.size
+    }
+}
+
 /// Field information.
 /// Enough to fill padding bytes between fields and
 /// inside the fields.
-#[derive(Clone, Copy)]
+///
+/// Same as with [`Field`], [`TypedField::new`] and accessor methods
+/// should be preferred over public fields.
+///
+/// [`Field`]: ./struct.Field.html
+/// [`TypedField::new`]: ./struct.TypedField.html#method.new
 pub struct TypedField<T: PaddingBane> {
+    /// Offset and size of the field.
     pub raw: Field,
+
+    /// Fields of the field's type.
     pub sub: T::Fields,
 }
 
+// Not derived as field types are not required to be `Copy`.
+impl<T> Clone for TypedField<T>
+where
+    T: PaddingBane,
+{
+    #[inline(always)]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for TypedField<T> where T: PaddingBane {}
+
+impl<T> fmt::Debug for TypedField<T>
+where
+    T: PaddingBane,
+    T::Fields: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("TypedField")
+            .field("raw", &self.raw)
+            .field("sub", &self.sub)
+            .finish()
+    }
+}
+
+impl<T> PartialEq for TypedField<T>
+where
+    T: PaddingBane,
+    T::Fields: PartialEq,
+{
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.raw == other.raw && self.sub == other.sub
+    }
+}
+
+impl<T> Eq for TypedField<T>
+where
+    T: PaddingBane,
+    T::Fields: Eq,
+{
+}
+
+impl<T> TypedField<T>
+where
+    T: PaddingBane,
+{
+    /// Creates field information from raw field information
+    /// and fields metadata of the field's type.
+    #[inline(always)]
+    pub fn new(raw: Field, sub: T::Fields) -> Self {
+        TypedField { raw, sub }
+    }
+
+    /// Returns offset and size of the field.
+    #[inline(always)]
+    pub fn raw(&self) -> Field {
+        self.raw
+    }
+
+    /// Returns fields metadata of the field's type.
+    #[inline(always)]
+    pub fn sub(&self) -> T::Fields {
+        self.sub
+    }
+
+    /// Initializes padding bytes of the field alone
+    /// and returns bytes representation of the field.
+    ///
+    /// # Safety
+    ///
+    /// `parent` must be bytes of the value this field information belongs to,
+    /// created by casting `&mut Parent`.
+    #[inline]
+    pub unsafe fn read_bytes<'a>(&self, parent: &'a mut [MaybeUninit<u8>]) -> &'a [u8] {
+        let bytes = &mut parent[self.raw.offset()..self.raw.end()];
+        T::init_padding(self.sub, bytes);
+        assume_slice_init(&*bytes)
+    }
+}
+
+/// Fills gaps between fields and after the last field
+/// till the end of the `bytes`.
+/// Sorts fields by offset.
+/// Returns number of bytes filled.
+fn fill_gaps(fields: &mut [Field], bytes: &mut [MaybeUninit<u8>]) -> usize {
+    fields.sort_unstable_by_key(|f| f.offset());
+    let mut count = 0;
+    let mut offset = 0;
+    for field in &*fields {
+        if field.offset() > offset {
+            fill_sentinel(&mut bytes[offset..field.offset()]);
+            count += field.offset() - offset;
+        }
+        // Zero-sized field may be sorted after field with the same offset.
+        offset = offset.max(field.end());
+    }
+
+    if bytes.len() > offset {
+        fill_sentinel(&mut bytes[offset..]);
+        count += bytes.len() - offset;
+    }
+    count
+}
+
+/// Fills bytes not covered by any field with padding sentinel
+/// and returns number of bytes filled.
+/// Sorts fields by offset.
+///
+/// Unlike code generated by derive macro, this function validates fields first
+/// and returns error without touching the bytes
+/// if any field is out of bounds or fields overlap.
+/// Index in [`SafeBytesError::FieldOutOfBounds`] refers to `fields`
+/// in order they were provided.
+/// It can be used in manual [`PaddingBane::init_padding`] implementations
+/// where layout is computed at runtime.
+///
+/// [`PaddingBane::init_padding`]: ./trait.PaddingBane.html#tymethod.init_padding
+/// [`SafeBytesError::FieldOutOfBounds`]: ./enum.SafeBytesError.html#variant.FieldOutOfBounds
+pub fn fill_padding(
+    fields: &mut [Field],
+    bytes: &mut [MaybeUninit<u8>],
+) -> Result<usize, SafeBytesError> {
+    for (index, field) in fields.iter().enumerate() {
+        match field.offset().checked_add(field.size()) {
+            Some(end) if end <= bytes.len() => {}
+            _ => return Err(SafeBytesError::FieldOutOfBounds { index }),
+        }
+    }
+
+    fields.sort_unstable_by_key(|f| f.offset());
+    let mut offset = 0;
+    for field in &*fields {
+        if field.size() == 0 {
+            // Zero-sized fields may share offset with other fields.
+            continue;
+        }
+        if field.offset() < offset {
+            return Err(SafeBytesError::FieldOverlap);
+        }
+        offset = field.end();
+    }
+
+    Ok(fill_gaps(fields, bytes))
+}
+
+/// Returns bytes of the value as possibly uninitialized bytes,
+/// without initializing padding.
+/// Allows driving [`PaddingBane::init_padding`] manually,
+/// e.g. to initialize padding of several values before copying them into shared buffer.
+///
+/// # Safety
+///
+/// Writes through returned slice must leave the value valid.
+/// They must not create invalid bit patterns for fields with restricted validity,
+/// like `bool`, `NonNull` or enums, and must not de-initialize bytes of fields.
+///
+/// ```
+/// # use safe_bytes::{uninit_bytes, PaddingBane, SafeBytes};
+/// #[derive(SafeBytes)]
+/// #[repr(C)]
+/// struct Entry {
+///     key: u8,
+///     value: u16,
+/// }
+///
+/// let mut entries = [Entry { key: 1, value: 2 }, Entry { key: 3, value: 4 }];
+/// let mut buffer = Vec::new();
+/// for entry in &mut entries {
+///     let fields = entry.get_fields();
+///     unsafe {
+///         let bytes = uninit_bytes(entry);
+///         Entry::init_padding(fields, bytes);
+///         buffer.extend(bytes.iter().map(|byte| byte.assume_init()));
+///     }
+/// }
+/// assert_eq!(buffer[1], 0xfe);
+/// assert_eq!(buffer[5], 0xfe);
+/// ```
+///
+/// [`PaddingBane::init_padding`]: ./trait.PaddingBane.html#tymethod.init_padding
+#[inline(always)]
+pub unsafe fn uninit_bytes<T>(value: &mut T) -> &mut [MaybeUninit<u8>]
+where
+    T: ?Sized,
+{
+    maybe_init_bytes_of(value)
+}
+
+#[doc(hidden)]
+pub fn __fill_gaps(fields: &mut [Field], bytes: &mut [MaybeUninit<u8>]) -> usize {
+    fill_gaps(fields, bytes)
+}
+
+/// Stores padding gaps in constant,
+/// so that [`PaddingBane::PADDING_MAP`] can borrow them for `'static`.
+///
+/// [`PaddingBane::PADDING_MAP`]: ./trait.PaddingBane.html#associatedconstant.PADDING_MAP
+#[doc(hidden)]
+pub trait __PaddingGaps<const G: usize> {
+    /// Array of gaps and number of gaps in it.
+    const GAPS: ([(usize, usize); G], usize);
+}
+
+/// Computes padding gaps of the value of `size` bytes with given fields.
+/// `G` must be at least `N + 1`.
+/// Returns array of gaps and number of gaps in it.
+#[doc(hidden)]
+pub const fn __padding_gaps<const N: usize, const G: usize>(
+    mut fields: [Field; N],
+    size: usize,
+) -> ([(usize, usize); G], usize) {
+    // Insertion sort, as sorting is not available in const context.
+    let mut i = 1;
+    while i < N {
+        let mut j = i;
+        while j > 0 && fields[j - 1].offset > fields[j].offset {
+            let field = fields[j];
+            fields[j] = fields[j - 1];
+            fields[j - 1] = field;
+            j -= 1;
+        }
+        i += 1;
+    }
+
+    let mut gaps = [(0, 0); G];
+    let mut count = 0;
+    let mut offset = 0;
+    let mut i = 0;
+    while i < N {
+        let field = fields[i];
+        if field.offset > offset {
+            gaps[count] = (offset, field.offset - offset);
+            count += 1;
+        }
+        if field.end() > offset {
+            offset = field.end();
+        }
+        i += 1;
+    }
+
+    if size > offset {
+        gaps[count] = (offset, size - offset);
+        count += 1;
+    }
+
+    (gaps, count)
+}
+
+/// Fills bytes with value used for padding.
+fn fill_sentinel(bytes: &mut [MaybeUninit<u8>]) {
+    __fill(bytes, 0xfe);
+}
+
+/// Runs shorter than this are filled with wide stores with `simd` feature.
+#[cfg(feature = "simd")]
+const WIDE_FILL_LIMIT: usize = 64;
+
+/// Fills bytes with the value.
+///
+/// Gaps between fields are usually a few bytes long,
+/// and `write_bytes` call dominates the cost of filling them.
+/// With `simd` feature short runs are filled with 16 bytes wide unaligned stores,
+/// while long runs are still left to `write_bytes`.
+#[doc(hidden)]
+#[inline(always)]
+pub fn __fill(bytes: &mut [MaybeUninit<u8>], value: u8) {
+    #[cfg(feature = "simd")]
+    {
+        let len = bytes.len();
+        if len < WIDE_FILL_LIMIT {
+            let ptr = bytes.as_mut_ptr() as *mut u8;
+            unsafe {
+                // Every store below is within `len` bytes.
+                // Stores may overlap to cover the tail without byte loop.
+                if len >= 16 {
+                    let mut offset = 0;
+                    while offset + 16 <= len {
+                        (ptr.add(offset) as *mut [u8; 16]).write_unaligned([value; 16]);
+                        offset += 16;
+                    }
+                    if offset < len {
+                        (ptr.add(len - 16) as *mut [u8; 16]).write_unaligned([value; 16]);
+                    }
+                } else if len >= 8 {
+                    (ptr as *mut [u8; 8]).write_unaligned([value; 8]);
+                    (ptr.add(len - 8) as *mut [u8; 8]).write_unaligned([value; 8]);
+                } else if len >= 4 {
+                    (ptr as *mut [u8; 4]).write_unaligned([value; 4]);
+                    (ptr.add(len - 4) as *mut [u8; 4]).write_unaligned([value; 4]);
+                } else {
+                    for offset in 0..len {
+                        ptr.add(offset).write(value);
+                    }
+                }
+            }
+            return;
+        }
+    }
+
+    unsafe {
+        // Writing `len` bytes into the slice is in bounds.
+        write_bytes(bytes.as_mut_ptr(), value, bytes.len());
+    }
+}
+
+/// Fills bytes with sentinel and then passes them to user-provided function.
+/// Bytes are initialized first, so the function can't leave them uninitialized.
+#[doc(hidden)]
+#[inline(always)]
+pub fn __fill_with(bytes: &mut [MaybeUninit<u8>], f: fn(&mut [u8])) {
+    fill_sentinel(bytes);
+    // All bytes are initialized above.
+    f(unsafe { assume_slice_init_mut(bytes) });
+}
+
+/// Initializes padding of the value with provided function
+/// and returns first `len` bytes of the value.
+///
+/// # Safety
+///
+/// `init` must initialize all padding bytes within first `len` bytes,
+/// and `len` must not exceed size of the value.
+#[doc(hidden)]
+#[inline(always)]
+pub unsafe fn __safe_bytes_with<T>(
+    value: &mut T,
+    len: usize,
+    init: impl FnOnce(T::Fields, &mut [MaybeUninit<u8>]),
+) -> &[u8]
+where
+    T: PaddingBane,
+{
+    let fields = value.get_fields();
+    let bytes = maybe_init_bytes_of(value);
+    init(fields, bytes);
+    assume_slice_init(&bytes[..len])
+}
+
+/// Returns bytes of the element with given index
+/// in bytes of an array or a slice of `T`.
+///
+/// With `unchecked_indexing` feature bounds are not checked.
+///
+/// # Safety
+///
+/// `bytes` must contain at least `index + 1` elements.
+#[doc(hidden)]
+#[inline(always)]
+pub unsafe fn __element_bytes<T>(
+    bytes: &mut [MaybeUninit<u8>],
+    index: usize,
+) -> &mut [MaybeUninit<u8>] {
+    let start = index * size_of::<T>();
+    let end = start + size_of::<T>();
+
+    #[cfg(feature = "unchecked_indexing")]
+    {
+        debug_assert!(end <= bytes.len());
+        bytes.get_unchecked_mut(start..end)
+    }
+
+    #[cfg(not(feature = "unchecked_indexing"))]
+    {
+        &mut bytes[start..end]
+    }
+}
+
 /// Returns maybe uninitialized bytes of the value.
 /// Intended for initializing padding bytes.
 ///
@@ -252,3 +1578,12 @@ unsafe fn maybe_init_bytes_of<T: ?Sized>(r: &mut T) -> &mut [MaybeUninit<u8>] {
 unsafe fn assume_slice_init<T>(slice: &[MaybeUninit<T>]) -> &[T] {
     from_raw_parts(slice.as_ptr() as *const T, size_of_val(slice))
 }
+
+/// Assume all elements of the mutable slice are initialized.
+///
+/// # Safety
+///
+/// All elements of the slice must be initialized.
+unsafe fn assume_slice_init_mut<T>(slice: &mut [MaybeUninit<T>]) -> &mut [T] {
+    from_raw_parts_mut(slice.as_mut_ptr() as *mut T, slice.len())
+}
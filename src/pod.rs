@@ -1,25 +1,28 @@
 use {
-    crate::PaddingBane,
+    crate::{PaddingBane, StaticFields},
     core::{
         marker::{PhantomData, PhantomPinned},
-        mem::MaybeUninit,
+        mem::{size_of, MaybeUninit},
         num::{
             NonZeroI128, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI8, NonZeroIsize, NonZeroU128,
             NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8, NonZeroUsize,
         },
+        ops::RangeFull,
         ptr::NonNull,
         sync::atomic::{
-            AtomicI16, AtomicI32, AtomicI64, AtomicI8, AtomicIsize, AtomicU16, AtomicU32,
-            AtomicU64, AtomicU8, AtomicUsize,
+            AtomicBool, AtomicI16, AtomicI32, AtomicI64, AtomicI8, AtomicIsize, AtomicPtr,
+            AtomicU16, AtomicU32, AtomicU64, AtomicU8, AtomicUsize,
         },
     },
 };
 
 macro_rules! impl_pod {
-    ($(<$($g:ident $(:$b:path)?),+>)? for $t:ty) => {
-        unsafe impl $(<$($g $(:$b)?),+>)? PaddingBane for $t {
+    ($(<$($g:tt),+>)? for $t:ty) => {
+        unsafe impl $(<$($g),+>)? PaddingBane for $t {
             type Fields = PhantomData<fn($t) -> $t>;
 
+            const NEEDS_PADDING: bool = false;
+
             #[inline(always)]
             fn get_fields(&self) -> Self::Fields {
                 PhantomData
@@ -28,11 +31,19 @@ macro_rules! impl_pod {
             #[inline(always)]
             unsafe fn init_padding(_fields: Self::Fields, _bytes: &mut [MaybeUninit<u8>]) {}
         }
+
+        unsafe impl $(<$($g),+>)? StaticFields for $t {
+            #[inline(always)]
+            fn fields() -> Self::Fields {
+                PhantomData
+            }
+        }
     };
 }
 
 impl_pod!(for ());
 impl_pod!(for bool);
+impl_pod!(for char);
 impl_pod!(for u8);
 impl_pod!(for i8);
 impl_pod!(for u16);
@@ -48,6 +59,7 @@ impl_pod!(for i128);
 impl_pod!(for f32);
 impl_pod!(for f64);
 
+impl_pod!(for AtomicBool);
 impl_pod!(for AtomicU8);
 impl_pod!(for AtomicI8);
 impl_pod!(for AtomicU16);
@@ -59,6 +71,20 @@ impl_pod!(for AtomicI64);
 impl_pod!(for AtomicUsize);
 impl_pod!(for AtomicIsize);
 
+// Non-zero integers have the same layout as primitive integers.
+impl_pod!(for NonZeroI8);
+impl_pod!(for NonZeroI16);
+impl_pod!(for NonZeroI32);
+impl_pod!(for NonZeroI64);
+impl_pod!(for NonZeroI128);
+impl_pod!(for NonZeroIsize);
+impl_pod!(for NonZeroU8);
+impl_pod!(for NonZeroU16);
+impl_pod!(for NonZeroU32);
+impl_pod!(for NonZeroU64);
+impl_pod!(for NonZeroU128);
+impl_pod!(for NonZeroUsize);
+
 impl_pod!(for Option<NonZeroI8>);
 impl_pod!(for Option<NonZeroI16>);
 impl_pod!(for Option<NonZeroI32>);
@@ -72,9 +98,61 @@ impl_pod!(for Option<NonZeroU64>);
 impl_pod!(for Option<NonZeroU128>);
 impl_pod!(for Option<NonZeroUsize>);
 
+// `None` is stored as invalid value of the whole scalar:
+// `2` for `bool` and a value above `char::MAX` for `char`.
+// Writing the scalar initializes all its bytes, and sizes are checked below,
+// so there are no bytes left for a separate tag.
+impl_pod!(for Option<bool>);
+impl_pod!(for Option<char>);
+
+const _: () = assert!(size_of::<Option<bool>>() == size_of::<bool>());
+const _: () = assert!(size_of::<Option<char>>() == size_of::<char>());
+
+// Pointers are plain addresses without padding.
+// Pointer-like newtypes may derive `SafeBytes` on top of these.
 impl_pod!(<T> for *mut T);
 impl_pod!(<T> for *const T);
+impl_pod!(<T> for NonNull<T>);
 impl_pod!(<T> for Option<NonNull<T>>);
+impl_pod!(<T> for AtomicPtr<T>);
+
+// Slice pointers are address and length, both always initialized.
+// `Option` of them isn't implemented, as `None` writes only the null address.
+// Pointers to trait objects are excluded, vtable pointer is not meant to be inspected.
+impl_pod!(<T> for *mut [T]);
+impl_pod!(<T> for *const [T]);
+impl_pod!(<T> for NonNull<[T]>);
+
+const _: () = assert!(size_of::<*const [u8]>() == 2 * size_of::<usize>());
+
+// References are non-null addresses, `Option` of them uses the null niche
+// and is laid out as `Option<NonNull<T>>`.
+impl_pod!(<'a, T> for Option<&'a T>);
+impl_pod!(<'a, T> for Option<&'a mut T>);
+
+// Function pointers are non-null addresses, `Option` of them uses the null niche.
+// Higher-ranked signatures, like `fn(&u8)`, are not covered.
+macro_rules! impl_pod_fn {
+    ($($a:ident),*) => {
+        impl_pod!(<R $(, $a)*> for fn($($a),*) -> R);
+        impl_pod!(<R $(, $a)*> for Option<fn($($a),*) -> R>);
+        impl_pod!(<R $(, $a)*> for unsafe fn($($a),*) -> R);
+        impl_pod!(<R $(, $a)*> for Option<unsafe fn($($a),*) -> R>);
+        impl_pod!(<R $(, $a)*> for extern "C" fn($($a),*) -> R);
+        impl_pod!(<R $(, $a)*> for Option<extern "C" fn($($a),*) -> R>);
+        impl_pod!(<R $(, $a)*> for unsafe extern "C" fn($($a),*) -> R);
+        impl_pod!(<R $(, $a)*> for Option<unsafe extern "C" fn($($a),*) -> R>);
+    };
+}
+
+impl_pod_fn!();
+impl_pod_fn!(A);
+impl_pod_fn!(A, B);
+impl_pod_fn!(A, B, C);
+impl_pod_fn!(A, B, C, D);
+impl_pod_fn!(A, B, C, D, E);
+impl_pod_fn!(A, B, C, D, E, F);
+
 impl_pod!(<T> for PhantomData<T>);
 impl_pod!(for PhantomPinned);
-impl_pod!(<T> for [T; 0]);
+impl_pod!(for RangeFull);
@@ -0,0 +1,90 @@
+use core::mem::size_of;
+
+/// Writes value in portable form: fields in declaration order
+/// without padding and numbers in little-endian byte order.
+/// Unlike [`SafeBytes`] output, it doesn't depend on target's layout and endianness.
+///
+/// Implemented for fixed-size integers, floats, `bool`, `()` and arrays of portable types.
+/// `usize`, `isize` and pointers have target-dependent size and don't implement it.
+/// Structs derive it with `#[safe_bytes(portable)]` attribute.
+///
+/// [`SafeBytes`]: ./trait.SafeBytes.html
+pub trait PortableBytes {
+    /// Size of the portable form.
+    const PORTABLE_SIZE: usize;
+
+    /// Writes portable form of the value into `out`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if length of `out` is not equal to [`PortableBytes::PORTABLE_SIZE`].
+    ///
+    /// [`PortableBytes::PORTABLE_SIZE`]: ./trait.PortableBytes.html#associatedconstant.PORTABLE_SIZE
+    fn write_portable(&self, out: &mut [u8]);
+
+    /// Writes portable form of the value into `buffer`
+    /// and returns written bytes.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `buffer` is shorter than [`PortableBytes::PORTABLE_SIZE`].
+    ///
+    /// [`PortableBytes::PORTABLE_SIZE`]: ./trait.PortableBytes.html#associatedconstant.PORTABLE_SIZE
+    #[inline]
+    fn portable_bytes<'a>(&self, buffer: &'a mut [u8]) -> &'a [u8] {
+        let out = &mut buffer[..Self::PORTABLE_SIZE];
+        self.write_portable(out);
+        out
+    }
+}
+
+macro_rules! impl_portable_number {
+    ($($t:ty),*) => {
+        $(
+            impl PortableBytes for $t {
+                const PORTABLE_SIZE: usize = size_of::<$t>();
+
+                #[inline(always)]
+                fn write_portable(&self, out: &mut [u8]) {
+                    out.copy_from_slice(&self.to_le_bytes());
+                }
+            }
+        )*
+    };
+}
+
+impl_portable_number!(u8, i8, u16, i16, u32, i32, u64, i64, u128, i128, f32, f64);
+
+impl PortableBytes for bool {
+    const PORTABLE_SIZE: usize = 1;
+
+    #[inline(always)]
+    fn write_portable(&self, out: &mut [u8]) {
+        out.copy_from_slice(&[*self as u8]);
+    }
+}
+
+impl PortableBytes for () {
+    const PORTABLE_SIZE: usize = 0;
+
+    #[inline(always)]
+    fn write_portable(&self, out: &mut [u8]) {
+        assert!(out.is_empty());
+    }
+}
+
+impl<T, const N: usize> PortableBytes for [T; N]
+where
+    T: PortableBytes,
+{
+    const PORTABLE_SIZE: usize = N * T::PORTABLE_SIZE;
+
+    #[inline]
+    fn write_portable(&self, out: &mut [u8]) {
+        assert_eq!(out.len(), Self::PORTABLE_SIZE);
+        for (index, element) in self.iter().enumerate() {
+            let start = index * T::PORTABLE_SIZE;
+            element.write_portable(&mut out[start..start + T::PORTABLE_SIZE]);
+        }
+    }
+}
@@ -0,0 +1,90 @@
+use {
+    crate::{
+        __PaddingGaps, __padding_gaps, fill_gaps, Field, PaddingBane, StaticFields, TypedField,
+    },
+    core::{
+        mem::{size_of, MaybeUninit},
+        ops::{RangeFrom, RangeTo},
+    },
+};
+
+// Range types have public fields but unspecified layout,
+// so offsets are taken from the fields.
+// `RangeInclusive` is not implemented, its private `exhausted` flag
+// can't be told apart from padding.
+macro_rules! impl_for_range {
+    ($type:ident . $field:ident) => {
+        unsafe impl<T> PaddingBane for $type<T>
+        where
+            T: PaddingBane,
+        {
+            type Fields = TypedField<T>;
+
+            const PADDING_MAP: &'static [(usize, usize)] = {
+                let gaps: &'static (_, usize) = &<Self as __PaddingGaps<2>>::GAPS;
+                gaps.0.split_at(gaps.1).0
+            };
+
+            const NEEDS_PADDING: bool =
+                !<Self as PaddingBane>::PADDING_MAP.is_empty() || T::NEEDS_PADDING;
+
+            #[inline(always)]
+            fn get_fields(&self) -> Self::Fields {
+                TypedField::new(
+                    Field::new(crate::__offset_of!(Self, $field), size_of::<T>()),
+                    self.$field.get_fields(),
+                )
+            }
+
+            #[inline(always)]
+            fn field(fields: Self::Fields, index: usize) -> Option<Field> {
+                match index {
+                    0 => Some(fields.raw()),
+                    _ => None,
+                }
+            }
+
+            #[inline]
+            unsafe fn init_padding(fields: Self::Fields, bytes: &mut [MaybeUninit<u8>]) {
+                Self::init_padding_counted(fields, bytes);
+            }
+
+            #[inline]
+            unsafe fn init_padding_counted(
+                fields: Self::Fields,
+                bytes: &mut [MaybeUninit<u8>],
+            ) -> usize {
+                let mut count = fill_gaps(&mut [fields.raw()], bytes);
+                let field_bytes = &mut bytes[fields.raw().offset()..fields.raw().end()];
+                count += T::init_padding_counted(fields.sub(), field_bytes);
+                count
+            }
+        }
+
+        unsafe impl<T> StaticFields for $type<T>
+        where
+            T: StaticFields,
+        {
+            #[inline(always)]
+            fn fields() -> Self::Fields {
+                TypedField::new(
+                    Field::new(crate::__offset_of!(Self, $field), size_of::<T>()),
+                    T::fields(),
+                )
+            }
+        }
+
+        impl<T> __PaddingGaps<2> for $type<T> {
+            const GAPS: ([(usize, usize); 2], usize) = __padding_gaps(
+                [Field::new(
+                    crate::__offset_of!(Self, $field),
+                    size_of::<T>(),
+                )],
+                size_of::<Self>(),
+            );
+        }
+    };
+}
+
+impl_for_range!(RangeFrom.start);
+impl_for_range!(RangeTo.end);
@@ -0,0 +1,98 @@
+use {
+    crate::{FromSafeBytes, SafeBytes},
+    core::{
+        fmt,
+        marker::PhantomData,
+        mem::{size_of, MaybeUninit},
+        slice::from_raw_parts_mut,
+    },
+    serde::{
+        de::{Error, SeqAccess, Visitor},
+        Deserialize, Deserializer, Serialize, Serializer,
+    },
+};
+
+/// Wrapper that serializes the value as its safe bytes
+/// and deserializes it back with [`FromSafeBytes`].
+///
+/// Value is copied before serialization, as padding can't be initialized
+/// through shared reference.
+///
+/// Bytes are in native endianness and layout,
+/// so they can be read back only on the same platform.
+///
+/// [`FromSafeBytes`]: ./trait.FromSafeBytes.html
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct SafeBytesSer<T>(pub T);
+
+impl<T> Serialize for SafeBytesSer<T>
+where
+    T: SafeBytes + Copy,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut copy = self.0;
+        serializer.serialize_bytes(copy.safe_bytes())
+    }
+}
+
+impl<'de, T> Deserialize<'de> for SafeBytesSer<T>
+where
+    T: FromSafeBytes,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer
+            .deserialize_bytes(SafeBytesVisitor(PhantomData))
+            .map(SafeBytesSer)
+    }
+}
+
+struct SafeBytesVisitor<T>(PhantomData<fn() -> T>);
+
+impl<'de, T> Visitor<'de> for SafeBytesVisitor<T>
+where
+    T: FromSafeBytes,
+{
+    type Value = T;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} bytes", size_of::<T>())
+    }
+
+    fn visit_bytes<E>(self, bytes: &[u8]) -> Result<T, E>
+    where
+        E: Error,
+    {
+        T::from_safe_bytes(bytes).map_err(|_| E::invalid_length(bytes.len(), &self))
+    }
+
+    // Formats without bytes type serialize them as sequences.
+    fn visit_seq<A>(self, mut seq: A) -> Result<T, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let mut value = MaybeUninit::<T>::uninit();
+        let bytes = unsafe {
+            // Bytes of the value, possibly uninitialized.
+            from_raw_parts_mut(value.as_mut_ptr() as *mut MaybeUninit<u8>, size_of::<T>())
+        };
+        for (index, byte) in bytes.iter_mut().enumerate() {
+            match seq.next_element()? {
+                Some(next) => *byte = MaybeUninit::new(next),
+                None => return Err(Error::invalid_length(index, &self)),
+            }
+        }
+        if seq.next_element::<u8>()?.is_some() {
+            return Err(Error::invalid_length(size_of::<T>() + 1, &self));
+        }
+
+        // All bytes are written and any bytes are valid value.
+        Ok(unsafe { value.assume_init() })
+    }
+}
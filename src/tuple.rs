@@ -0,0 +1,96 @@
+use {
+    crate::{
+        __PaddingGaps, __padding_gaps, fill_gaps, Field, PaddingBane, StaticFields, TypedField,
+    },
+    core::mem::{size_of, MaybeUninit},
+};
+
+macro_rules! impl_for_tuple {
+    ($($a:ident . $i:tt),+) => {
+        unsafe impl<$($a),+> PaddingBane for ($($a,)+)
+        where
+            $($a: PaddingBane,)+
+        {
+            type Fields = ($(TypedField<$a>,)+);
+
+            const PADDING_MAP: &'static [(usize, usize)] = {
+                let gaps: &'static (_, usize) =
+                    &<Self as __PaddingGaps<{ [$($i),+].len() + 1 }>>::GAPS;
+                gaps.0.split_at(gaps.1).0
+            };
+
+            const NEEDS_PADDING: bool =
+                !<Self as PaddingBane>::PADDING_MAP.is_empty() $(|| $a::NEEDS_PADDING)+;
+
+            #[inline(always)]
+            fn get_fields(&self) -> Self::Fields {
+                let base = self as *const Self as usize;
+                ($(
+                    TypedField::new(
+                        Field::new(&self.$i as *const $a as usize - base, size_of::<$a>()),
+                        self.$i.get_fields(),
+                    ),
+                )+)
+            }
+
+            #[inline(always)]
+            fn field(fields: Self::Fields, index: usize) -> Option<Field> {
+                match index {
+                    $($i => Some(fields.$i.raw()),)+
+                    _ => None,
+                }
+            }
+
+            #[inline]
+            unsafe fn init_padding(fields: Self::Fields, bytes: &mut [MaybeUninit<u8>]) {
+                Self::init_padding_counted(fields, bytes);
+            }
+
+            #[inline]
+            unsafe fn init_padding_counted(fields: Self::Fields, bytes: &mut [MaybeUninit<u8>]) -> usize {
+                let mut count = fill_gaps(&mut [$(fields.$i.raw(),)+], bytes);
+                $(
+                    let field = fields.$i;
+                    let field_bytes = &mut bytes[field.raw().offset()..field.raw().end()];
+                    count += <$a as PaddingBane>::init_padding_counted(field.sub(), field_bytes);
+                )+
+                count
+            }
+        }
+
+        unsafe impl<$($a),+> StaticFields for ($($a,)+)
+        where
+            $($a: StaticFields,)+
+        {
+            #[inline(always)]
+            fn fields() -> Self::Fields {
+                ($(
+                    TypedField::new(
+                        Field::new(crate::__offset_of!(Self, $i), size_of::<$a>()),
+                        $a::fields(),
+                    ),
+                )+)
+            }
+        }
+
+        impl<$($a),+> __PaddingGaps<{ [$($i),+].len() + 1 }> for ($($a,)+) {
+            const GAPS: ([(usize, usize); [$($i),+].len() + 1], usize) = __padding_gaps(
+                [$(Field::new(crate::__offset_of!(Self, $i), size_of::<$a>()),)+],
+                size_of::<Self>(),
+            );
+        }
+    };
+}
+
+impl_for_tuple!(A.0);
+impl_for_tuple!(A.0, B.1);
+impl_for_tuple!(A.0, B.1, C.2);
+impl_for_tuple!(A.0, B.1, C.2, D.3);
+impl_for_tuple!(A.0, B.1, C.2, D.3, E.4);
+impl_for_tuple!(A.0, B.1, C.2, D.3, E.4, F.5);
+impl_for_tuple!(A.0, B.1, C.2, D.3, E.4, F.5, G.6);
+impl_for_tuple!(A.0, B.1, C.2, D.3, E.4, F.5, G.6, H.7);
+impl_for_tuple!(A.0, B.1, C.2, D.3, E.4, F.5, G.6, H.7, I.8);
+impl_for_tuple!(A.0, B.1, C.2, D.3, E.4, F.5, G.6, H.7, I.8, J.9);
+impl_for_tuple!(A.0, B.1, C.2, D.3, E.4, F.5, G.6, H.7, I.8, J.9, K.10);
+impl_for_tuple!(A.0, B.1, C.2, D.3, E.4, F.5, G.6, H.7, I.8, J.9, K.10, L.11);
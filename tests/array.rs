@@ -0,0 +1,231 @@
+mod common;
+
+use {
+    common::Example2,
+    core::num::{NonZeroU16, NonZeroU32},
+    safe_bytes::SafeBytes,
+};
+
+const MY_LEN: usize = 12;
+
+#[derive(SafeBytes)]
+#[repr(C)]
+struct Named {
+    a: u32,
+    b: [u8; MY_LEN],
+    c: u64,
+}
+
+#[test]
+fn named_const_length() {
+    let mut named = Named {
+        a: 1,
+        b: [2; MY_LEN],
+        c: 3,
+    };
+    let fields = safe_bytes::PaddingBane::get_fields(&named);
+    assert_eq!(fields.1.raw.offset, 4);
+    assert_eq!(fields.1.raw.size, MY_LEN);
+
+    let bytes = named.safe_bytes();
+    assert_eq!(bytes.len(), 24);
+    assert_eq!(&bytes[..4], &1u32.to_ne_bytes());
+    assert_eq!(&bytes[4..16], &[2; MY_LEN]);
+    assert_eq!(&bytes[16..], &3u64.to_ne_bytes());
+}
+
+#[test]
+fn padded_elements() {
+    let mut array = [
+        Example2::new(1, 2, 3),
+        Example2::new(4, 5, 6),
+        Example2::new(7, 8, 9),
+    ];
+    let expected = array
+        .iter()
+        .flat_map(Example2::expected)
+        .collect::<Vec<_>>();
+    assert_eq!(array.safe_bytes(), &expected[..]);
+}
+
+#[derive(SafeBytes)]
+#[repr(C)]
+struct Outer {
+    tag: u16,
+    inner: [Example2; 4],
+    tail: u8,
+}
+
+#[test]
+fn nested_array_field() {
+    let mut outer = Outer {
+        tag: 10,
+        inner: [
+            Example2::new(1, 2, 3),
+            Example2::new(4, 5, 6),
+            Example2::new(7, 8, 9),
+            Example2::new(10, 11, 12),
+        ],
+        tail: 13,
+    };
+
+    let expected = [
+        10u16.to_ne_bytes().to_vec(),
+        vec![0xfe; 6],
+        outer
+            .inner
+            .iter()
+            .flat_map(Example2::expected)
+            .collect::<Vec<_>>(),
+        vec![13],
+        vec![0xfe; 7],
+    ]
+    .concat();
+    assert_eq!(outer.safe_bytes(), &expected[..]);
+}
+
+#[derive(SafeBytes)]
+#[repr(C)]
+struct WithEmpty {
+    a: u8,
+    empty: [Example2; 0],
+    b: u32,
+}
+
+#[test]
+fn empty_arrays() {
+    let mut ints: [u32; 0] = [];
+    assert!(ints.safe_bytes().is_empty());
+
+    let mut structs: [Example2; 0] = [];
+    assert!(structs.safe_bytes().is_empty());
+
+    let mut with_empty = WithEmpty {
+        a: 1,
+        empty: [],
+        b: 2,
+    };
+    let expected = [
+        vec![1],
+        vec![0xfe; 7],
+        2u32.to_ne_bytes().to_vec(),
+        vec![0xfe; 4],
+    ]
+    .concat();
+    assert_eq!(with_empty.safe_bytes(), &expected[..]);
+}
+
+#[derive(SafeBytes)]
+#[safe_bytes(unroll = 2)]
+#[repr(C)]
+struct Unroll {
+    unrolled: [Example2; 2],
+    looped: [Example2; 3],
+    nested: ([Example2; 1], u8),
+}
+
+#[test]
+fn unrolled_array_fields() {
+    use safe_bytes::PaddingBane;
+
+    let mut unroll = Unroll {
+        unrolled: [Example2::new(1, 2, 3), Example2::new(4, 5, 6)],
+        looped: [
+            Example2::new(7, 8, 9),
+            Example2::new(10, 11, 12),
+            Example2::new(13, 14, 15),
+        ],
+        nested: ([Example2::new(16, 17, 18)], 19),
+    };
+    let fields = unroll.get_fields();
+    let count = unsafe {
+        let bytes = core::slice::from_raw_parts_mut(
+            &mut unroll as *mut _ as *mut core::mem::MaybeUninit<u8>,
+            core::mem::size_of_val(&unroll),
+        );
+        Unroll::init_padding_counted(fields, bytes)
+    };
+    assert_eq!(count, 6 * 13 + 7);
+
+    let expected = [
+        unroll
+            .unrolled
+            .iter()
+            .chain(&unroll.looped)
+            .chain(&unroll.nested.0)
+            .flat_map(Example2::expected)
+            .collect::<Vec<_>>(),
+        vec![19],
+        vec![0xfe; 7],
+    ]
+    .concat();
+    assert_eq!(unroll.safe_bytes(), &expected[..]);
+}
+
+#[derive(SafeBytes)]
+#[repr(C)]
+struct Large {
+    tag: u8,
+    data: [u8; 100_000],
+    len: u32,
+}
+
+#[test]
+fn large_array() {
+    let mut large = Box::new(Large {
+        tag: 1,
+        data: [2; 100_000],
+        len: 3,
+    });
+
+    let bytes = large.safe_bytes();
+    assert_eq!(bytes.len(), 100_008);
+    assert_eq!(bytes[0], 1);
+    assert!(bytes[1..100_001].iter().all(|&b| b == 2));
+    assert_eq!(&bytes[100_001..100_004], &[0xfe; 3]);
+    assert_eq!(&bytes[100_004..], &3u32.to_ne_bytes());
+}
+
+/// Fixed-size C string buffer, as found in FFI structs.
+#[derive(SafeBytes)]
+#[repr(C)]
+struct CName {
+    name: [core::ffi::c_char; 30],
+    id: u32,
+}
+
+#[test]
+fn c_char_buffer() {
+    let mut named = CName {
+        name: [0; 30],
+        id: 7,
+    };
+    for (dst, src) in named.name.iter_mut().zip(b"device") {
+        *dst = *src as core::ffi::c_char;
+    }
+
+    let mut expected = b"device".to_vec();
+    expected.resize(30, 0);
+    expected.extend_from_slice(&[0xfe, 0xfe]);
+    expected.extend_from_slice(&7u32.to_ne_bytes());
+    assert_eq!(named.safe_bytes(), &expected[..]);
+}
+
+#[test]
+fn non_zero_elements() {
+    let mut ids = [1, 2, 3, 4, 5, 6, 7, u32::MAX].map(|id| NonZeroU32::new(id).unwrap());
+    let expected = ids
+        .iter()
+        .flat_map(|id| id.get().to_ne_bytes())
+        .collect::<Vec<_>>();
+    assert_eq!(ids.safe_bytes(), &expected[..]);
+
+    let mut ports = [0x0102, 0x0304, 0x0506, 0xfffe].map(|port| NonZeroU16::new(port).unwrap());
+
+    #[cfg(target_endian = "little")]
+    let expected = [0x02, 0x01, 0x04, 0x03, 0x06, 0x05, 0xfe, 0xff];
+    #[cfg(target_endian = "big")]
+    let expected = [0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0xff, 0xfe];
+
+    assert_eq!(ports.safe_bytes(), &expected);
+}
@@ -0,0 +1,75 @@
+use {
+    core::sync::atomic::{AtomicBool, AtomicU16, AtomicU8, Ordering},
+    safe_bytes::SafeBytes,
+};
+
+#[test]
+fn atomic_bool_bitmap() {
+    let mut flags: [AtomicBool; 64] = core::array::from_fn(|_| AtomicBool::new(false));
+    for (i, flag) in flags.iter().enumerate() {
+        flag.store(i % 3 == 0, Ordering::Relaxed);
+    }
+    let expected = (0..64).map(|i| (i % 3 == 0) as u8).collect::<Vec<_>>();
+    assert_eq!(flags.safe_bytes(), &expected[..]);
+}
+
+#[derive(SafeBytes)]
+#[repr(C)]
+struct Flags {
+    ready: AtomicBool,
+    count: AtomicU16,
+    bits: [AtomicBool; 3],
+}
+
+#[test]
+fn padded_atomics() {
+    let mut flags = Flags {
+        ready: AtomicBool::new(true),
+        count: AtomicU16::new(2),
+        bits: [
+            AtomicBool::new(false),
+            AtomicBool::new(true),
+            AtomicBool::new(true),
+        ],
+    };
+    flags.bits[0].fetch_or(true, Ordering::SeqCst);
+
+    let expected = [
+        vec![1, 0xfe],
+        2u16.to_ne_bytes().to_vec(),
+        vec![1, 1, 1, 0xfe],
+    ]
+    .concat();
+    assert_eq!(flags.safe_bytes(), &expected[..]);
+}
+
+/// Lock-free byte ring buffer.
+#[derive(SafeBytes)]
+#[repr(C)]
+struct Ring {
+    head: AtomicU16,
+    tail: AtomicU16,
+    buffer: [AtomicU8; 256],
+}
+
+#[test]
+fn atomic_byte_ring() {
+    let mut buffer: [AtomicU8; 256] = core::array::from_fn(|i| AtomicU8::new(i as u8));
+    buffer[7].fetch_add(100, Ordering::SeqCst);
+
+    let mut expected = (0..=255u8).collect::<Vec<_>>();
+    expected[7] += 100;
+    assert_eq!(buffer.safe_bytes(), &expected[..]);
+
+    let mut ring = Ring {
+        head: AtomicU16::new(1),
+        tail: AtomicU16::new(2),
+        buffer,
+    };
+    ring.buffer[255].store(0, Ordering::Release);
+    expected[255] = 0;
+
+    let bytes = ring.safe_bytes();
+    assert_eq!(bytes.len(), 260);
+    assert_eq!(&bytes[4..], &expected[..]);
+}
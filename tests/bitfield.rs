@@ -0,0 +1,38 @@
+#![cfg(feature = "bitfield")]
+
+use safe_bytes::{bitfield::BitField, SafeBytes};
+
+#[derive(SafeBytes)]
+#[repr(C)]
+struct Flags {
+    kind: u8,
+    bits: BitField<u32>,
+}
+
+#[test]
+fn bit_ranges() {
+    let mut bits = BitField::new(0u32);
+    bits.set(0, 3, 0b101);
+    bits.set(3, 1, 1);
+    bits.set(28, 4, 0xff);
+    assert_eq!(bits.get(0, 3), 0b101);
+    assert_eq!(bits.get(3, 1), 1);
+    assert_eq!(bits.get(28, 4), 0xf);
+    assert_eq!(bits.get(0, 32), 0xf000_000d);
+    assert_eq!(bits.get(4, 0), 0);
+
+    let mut flags = Flags { kind: 7, bits };
+    let expected = [
+        vec![7],
+        vec![0xfe; 3],
+        0xf000_000du32.to_ne_bytes().to_vec(),
+    ]
+    .concat();
+    assert_eq!(flags.safe_bytes(), &expected[..]);
+}
+
+#[test]
+#[should_panic]
+fn out_of_range() {
+    BitField::new(0u8).get(4, 5);
+}
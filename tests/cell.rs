@@ -0,0 +1,145 @@
+mod common;
+
+use {
+    common::Example2,
+    core::{
+        cell::{Cell, UnsafeCell},
+        sync::atomic::{AtomicU32, Ordering},
+    },
+    safe_bytes::{PaddingBane, SafeBytes, SafeBytesCell},
+};
+
+#[derive(SafeBytes)]
+#[repr(C)]
+struct Counter {
+    tag: u8,
+    count: AtomicU32,
+}
+
+#[test]
+fn cache_invalidation() {
+    let mut cell = SafeBytesCell::new(Example2::new(1, 2, 3));
+    assert_eq!(cell.as_bytes(), &Example2::new(1, 2, 3).expected()[..]);
+    assert_eq!(cell.generation(), 0);
+
+    // Cache hit.
+    assert_eq!(cell.as_bytes(), &Example2::new(1, 2, 3).expected()[..]);
+
+    // Mutable access invalidates the cache.
+    cell.get_mut().b = 5;
+    assert_eq!(cell.generation(), 1);
+    assert_eq!(cell.as_bytes(), &Example2::new(1, 5, 3).expected()[..]);
+    assert_eq!(cell.into_inner().b, 5);
+}
+
+#[test]
+fn interior_mutability() {
+    let mut cell = SafeBytesCell::new(Counter {
+        tag: 1,
+        count: AtomicU32::new(2),
+    });
+    let expected = |count: u32| [vec![1, 0xfe, 0xfe, 0xfe], count.to_ne_bytes().to_vec()].concat();
+    assert_eq!(cell.as_bytes(), &expected(2)[..]);
+
+    // Change through shared reference is not tracked and cached bytes are stale.
+    cell.get().count.store(3, Ordering::Relaxed);
+    assert_eq!(cell.as_bytes(), &expected(2)[..]);
+
+    cell.bump();
+    assert_eq!(cell.as_bytes(), &expected(3)[..]);
+}
+
+#[test]
+fn cell_array() {
+    let mut cells: [Cell<u32>; 8] = Default::default();
+    for (i, cell) in cells.iter().enumerate() {
+        cell.set(i as u32);
+    }
+    let expected = (0..8u32).flat_map(u32::to_ne_bytes).collect::<Vec<_>>();
+    assert_eq!(cells.safe_bytes(), &expected[..]);
+}
+
+#[derive(SafeBytes)]
+#[repr(C)]
+struct Counters {
+    hits: Cell<u8>,
+    last: [Cell<Example2>; 2],
+}
+
+#[test]
+fn padded_cells() {
+    let mut counters = Counters {
+        hits: Cell::new(1),
+        last: [
+            Cell::new(Example2::new(2, 3, 4)),
+            Cell::new(Example2::new(5, 6, 7)),
+        ],
+    };
+
+    // Interior mutability through shared references before taking bytes.
+    let shared = &counters;
+    shared.hits.set(shared.hits.get() + 1);
+    shared.last[1].set(Example2::new(8, 9, 10));
+
+    let expected = [
+        vec![2],
+        vec![0xfe; 7],
+        Example2::new(2, 3, 4).expected(),
+        Example2::new(8, 9, 10).expected(),
+    ]
+    .concat();
+    assert_eq!(counters.safe_bytes(), &expected[..]);
+}
+
+#[derive(SafeBytes)]
+#[repr(C)]
+struct Slot {
+    ready: u8,
+    value: UnsafeCell<Example2>,
+}
+
+#[test]
+fn unsafe_cell() {
+    let mut slot = Slot {
+        ready: 1,
+        value: UnsafeCell::new(Example2::new(2, 3, 4)),
+    };
+
+    // Value is replaced through shared reference, as a lock would do.
+    let shared = &slot;
+    unsafe { *shared.value.get() = Example2::new(5, 6, 7) };
+
+    let expected = [vec![1], vec![0xfe; 7], Example2::new(5, 6, 7).expected()].concat();
+    assert_eq!(slot.safe_bytes(), &expected[..]);
+
+    let mut cells = [UnsafeCell::new(1u32), UnsafeCell::new(2)];
+    let expected = [1u32.to_ne_bytes(), 2u32.to_ne_bytes()].concat();
+    assert_eq!(cells.safe_bytes(), &expected[..]);
+}
+
+#[test]
+fn cell_array_nesting() {
+    let values = [1u32, 2, 3, 4];
+    let expected = values
+        .iter()
+        .flat_map(|v| v.to_ne_bytes())
+        .collect::<Vec<_>>();
+
+    let mut outer = Cell::new(values);
+    let mut inner = values.map(Cell::new);
+    assert_eq!(outer.safe_bytes(), &expected[..]);
+    assert_eq!(inner.safe_bytes(), &expected[..]);
+
+    let padded = [Example2::new(1, 2, 3), Example2::new(4, 5, 6)];
+    let mut outer = Cell::new(padded);
+    let mut inner = padded.map(Cell::new);
+    let outer_bytes = outer.safe_bytes().to_vec();
+    assert_eq!(
+        outer_bytes,
+        [padded[0].expected(), padded[1].expected()].concat()
+    );
+    assert_eq!(inner.safe_bytes(), &outer_bytes[..]);
+
+    // Both nesting orders describe the layout with the same fields.
+    assert_eq!(outer.get_fields(), inner.get_fields());
+}
@@ -0,0 +1,30 @@
+#![allow(dead_code)]
+
+use safe_bytes::SafeBytes;
+
+/// Same struct as in `examples/derive.rs`.
+#[derive(Clone, Copy, SafeBytes)]
+#[repr(C)]
+pub struct Example2 {
+    pub a: u8,
+    pub b: u64,
+    pub c: u16,
+}
+
+impl Example2 {
+    pub fn new(a: u8, b: u64, c: u16) -> Self {
+        Example2 { a, b, c }
+    }
+
+    /// Expected safe bytes of the value.
+    pub fn expected(&self) -> Vec<u8> {
+        [
+            vec![self.a],
+            vec![0xfe; 7],
+            self.b.to_ne_bytes().to_vec(),
+            self.c.to_ne_bytes().to_vec(),
+            vec![0xfe; 6],
+        ]
+        .concat()
+    }
+}
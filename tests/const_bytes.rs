@@ -0,0 +1,53 @@
+#![cfg(safe_bytes_const_mut)]
+
+use {
+    core::num::Wrapping,
+    safe_bytes::{safe_bytes_const, SafeBytes},
+};
+
+#[derive(SafeBytes)]
+#[repr(C)]
+struct Dense {
+    a: u32,
+    b: [u16; 2],
+    c: Wrapping<u64>,
+}
+
+const DENSE_BYTES: [u8; 16] = {
+    let mut dense = Dense {
+        a: 0x0102_0304,
+        b: [5, 6],
+        c: Wrapping(7),
+    };
+    let mut bytes = [0; 16];
+    bytes.copy_from_slice(safe_bytes_const(&mut dense));
+    bytes
+};
+
+const INT_BYTES: [u8; 4] = {
+    let mut value = 0x0a0b_0c0du32;
+    let mut bytes = [0; 4];
+    bytes.copy_from_slice(safe_bytes_const(&mut value));
+    bytes
+};
+
+#[test]
+fn const_bytes() {
+    let expected = [
+        0x0102_0304u32.to_ne_bytes().to_vec(),
+        5u16.to_ne_bytes().to_vec(),
+        6u16.to_ne_bytes().to_vec(),
+        7u64.to_ne_bytes().to_vec(),
+    ]
+    .concat();
+    assert_eq!(DENSE_BYTES[..], expected[..]);
+
+    let mut dense = Dense {
+        a: 0x0102_0304,
+        b: [5, 6],
+        c: Wrapping(7),
+    };
+    assert_eq!(dense.safe_bytes(), &DENSE_BYTES[..]);
+
+    assert_eq!(INT_BYTES, 0x0a0b_0c0du32.to_ne_bytes());
+}
@@ -0,0 +1,38 @@
+#![cfg(feature = "alloc")]
+
+mod common;
+
+use {
+    common::Example2,
+    safe_bytes::{debug_bytes, SafeBytes},
+};
+
+#[test]
+fn example_dump() {
+    // Byte-symmetric values keep the dump independent of endianness.
+    let mut example = Example2::new(1, 0x0202_0202_0202_0202, 0x0303);
+    assert_eq!(
+        debug_bytes(&mut example),
+        "00: 01 [0] | 01: fe fe fe fe fe fe fe [pad] \
+         | 08: 02 02 02 02 02 02 02 02 [1] | 10: 03 03 [2] | 12: fe fe fe fe fe fe [pad]"
+    );
+}
+
+#[derive(SafeBytes)]
+#[repr(C)]
+struct TrailingPadding {
+    a: u32,
+    b: u8,
+}
+
+#[test]
+fn fields_dump() {
+    assert_eq!(
+        debug_bytes(&mut TrailingPadding {
+            a: 0x0404_0404,
+            b: 5
+        }),
+        "00: 04 04 04 04 [0] | 04: 05 [1] | 05: fe fe fe [pad]"
+    );
+    assert_eq!(debug_bytes(&mut 0x0707u16), "00: 07 07");
+}
@@ -0,0 +1,699 @@
+mod common;
+
+use {
+    common::Example2,
+    core::{num::Wrapping, ptr::null_mut},
+    safe_bytes::{Field, FromSafeBytes, PaddingBane, SafeBytes},
+};
+
+#[derive(SafeBytes)]
+#[safe_bytes(no_inline)]
+#[repr(C)]
+struct NoInline {
+    a: u8,
+    b: u64,
+    c: u16,
+}
+
+#[derive(SafeBytes)]
+#[safe_bytes(pad_per_field)]
+#[repr(C)]
+struct PerField {
+    a: u8,
+    b: u32,
+    c: u8,
+    d: u64,
+    e: u8,
+}
+
+#[derive(SafeBytes)]
+#[safe_bytes(pad_per_field)]
+#[repr(C)]
+struct ManyFields {
+    f0: u8,
+    f1: u16,
+    f2: u8,
+    f3: u16,
+    f4: u8,
+    f5: u16,
+    f6: u8,
+    f7: u16,
+    f8: u8,
+    f9: u16,
+    f10: u8,
+    f11: u16,
+    f12: u8,
+    f13: u16,
+    f14: u8,
+    f15: u16,
+}
+
+/// Fills padding with alternating `0xaa` and `0xbb` bytes.
+fn canary(bytes: &mut [u8]) {
+    for (index, byte) in bytes.iter_mut().enumerate() {
+        *byte = if index % 2 == 0 { 0xaa } else { 0xbb };
+    }
+}
+
+#[derive(SafeBytes)]
+#[safe_bytes(pad_with_fn = "canary")]
+#[repr(C)]
+struct Canary {
+    a: u8,
+    b: u32,
+    c: u16,
+    d: Example2,
+}
+
+#[derive(Clone, Copy, SafeBytes)]
+#[repr(C)]
+struct Inner {
+    a: u8,
+    b: u32,
+}
+
+// Any bytes are valid for `u8` and `u32` fields.
+unsafe impl FromSafeBytes for Inner {}
+
+#[derive(SafeBytes)]
+#[repr(C)]
+struct Outer {
+    #[safe_bytes(recurse)]
+    recursed: Inner,
+    #[safe_bytes(opaque)]
+    opaque: Inner,
+    tag: u16,
+}
+
+#[derive(SafeBytes)]
+#[safe_bytes(no_tail_pad)]
+#[repr(C)]
+struct NoTail {
+    a: u8,
+    b: u32,
+    c: u8,
+}
+
+#[derive(SafeBytes)]
+#[safe_bytes(no_tail_pad)]
+#[repr(C)]
+struct NoTailFull {
+    a: u16,
+    b: u16,
+}
+
+#[derive(SafeBytes)]
+#[repr(C)]
+struct ComplexFields<T> {
+    flag: u8,
+    ptr: *const T,
+    data: [u32; 2],
+    wrapped: Wrapping<u64>,
+    reference: *mut *const u8,
+}
+
+#[derive(SafeBytes)]
+#[repr(C)]
+struct Tuple(u8, u32, u16);
+
+/// Compiler is free to reorder fields of this struct.
+#[derive(SafeBytes)]
+#[safe_bytes(declaration_order)]
+struct Reordered {
+    a: u8,
+    b: u64,
+    c: u16,
+    d: Example2,
+}
+
+#[test]
+fn derive() {
+    let mut example = Example2::new(1, 2, 3);
+    let expected = example.expected();
+    assert_eq!(example.safe_bytes(), &expected[..]);
+}
+
+#[test]
+fn no_inline() {
+    let mut example = NoInline { a: 1, b: 2, c: 3 };
+    let expected = Example2::new(1, 2, 3).expected();
+    assert_eq!(example.safe_bytes(), &expected[..]);
+}
+
+#[test]
+fn field_accessors() {
+    let example = Example2::new(1, 2, 3);
+    let (a, b, c) = safe_bytes::PaddingBane::get_fields(&example);
+    assert_eq!((a.raw().offset(), a.raw().size(), a.raw().end()), (0, 1, 1));
+    assert_eq!(
+        (b.raw().offset(), b.raw().size(), b.raw().end()),
+        (8, 8, 16)
+    );
+    assert_eq!(
+        (c.raw().offset(), c.raw().size(), c.raw().end()),
+        (16, 2, 18)
+    );
+
+    let field = safe_bytes::Field::new(4, 2);
+    assert_eq!((field.offset(), field.size(), field.end()), (4, 2, 6));
+}
+
+#[test]
+fn read_field_bytes() {
+    use core::mem::{size_of, MaybeUninit};
+
+    let mut example = Example2::new(1, 0x0102_0304_0506_0708, 3);
+    let (_, b, _) = safe_bytes::PaddingBane::get_fields(&example);
+    let bytes = unsafe {
+        let parent = core::slice::from_raw_parts_mut(
+            &mut example as *mut Example2 as *mut MaybeUninit<u8>,
+            size_of::<Example2>(),
+        );
+        b.read_bytes(parent)
+    };
+    assert_eq!(bytes, &0x0102_0304_0506_0708u64.to_ne_bytes());
+}
+
+#[test]
+fn padding_count() {
+    use safe_bytes::PaddingBane;
+
+    let mut example = [Example2::new(1, 2, 3), Example2::new(4, 5, 6)];
+    let fields = example.get_fields();
+    let count = unsafe {
+        let bytes = core::slice::from_raw_parts_mut(
+            &mut example as *mut _ as *mut core::mem::MaybeUninit<u8>,
+            core::mem::size_of_val(&example),
+        );
+        <[Example2; 2]>::init_padding_counted(fields, bytes)
+    };
+    assert_eq!(count, 26);
+    assert_eq!(
+        example[1].safe_bytes(),
+        &Example2::new(4, 5, 6).expected()[..]
+    );
+}
+
+#[test]
+fn pad_with_fn() {
+    let mut example = Canary {
+        a: 1,
+        b: 2,
+        c: 3,
+        d: Example2::new(4, 5, 6),
+    };
+    let expected = [
+        vec![1],
+        vec![0xaa, 0xbb, 0xaa],
+        2u32.to_ne_bytes().to_vec(),
+        3u16.to_ne_bytes().to_vec(),
+        vec![0xaa, 0xbb, 0xaa, 0xbb, 0xaa, 0xbb],
+        Example2::new(4, 5, 6).expected(),
+    ]
+    .concat();
+    assert_eq!(example.safe_bytes(), &expected[..]);
+}
+
+#[test]
+fn opaque_field() {
+    let inner = Inner { a: 1, b: 2 };
+    let mut outer = Outer {
+        recursed: inner,
+        opaque: inner,
+        tag: 3,
+    };
+    let expected = [
+        vec![1, 0xfe, 0xfe, 0xfe],
+        2u32.to_ne_bytes().to_vec(),
+        vec![0xfe; 8],
+        3u16.to_ne_bytes().to_vec(),
+        vec![0xfe; 2],
+    ]
+    .concat();
+    assert_eq!(outer.safe_bytes(), &expected[..]);
+
+    // Opaque field is overwritten in place, recursed one keeps its value.
+    assert_eq!((outer.recursed.a, outer.recursed.b), (1, 2));
+    assert_eq!((outer.opaque.a, outer.opaque.b), (0xfe, 0xfefe_fefe));
+}
+
+#[test]
+fn no_tail_pad() {
+    assert_eq!(NoTail::NO_TAIL_SIZE, 9);
+    let mut example = NoTail { a: 1, b: 2, c: 3 };
+    let expected = [
+        vec![1, 0xfe, 0xfe, 0xfe],
+        2u32.to_ne_bytes().to_vec(),
+        vec![3],
+    ]
+    .concat();
+    assert_eq!(example.safe_bytes_no_tail(), &expected[..]);
+
+    // `PaddingBane` still fills the tail.
+    let expected = [expected, vec![0xfe; 3]].concat();
+    assert_eq!(example.safe_bytes(), &expected[..]);
+
+    assert_eq!(NoTailFull::NO_TAIL_SIZE, 4);
+    let mut full = NoTailFull { a: 1, b: 2 };
+    let expected = [1u16.to_ne_bytes(), 2u16.to_ne_bytes()].concat();
+    assert_eq!(full.safe_bytes_no_tail(), &expected[..]);
+}
+
+#[test]
+fn pad_per_field() {
+    let mut example = PerField {
+        a: 1,
+        b: 2,
+        c: 3,
+        d: 4,
+        e: 5,
+    };
+    let expected = [
+        vec![1],
+        vec![0xf0; 3],
+        2u32.to_ne_bytes().to_vec(),
+        vec![3],
+        vec![0xf2; 7],
+        4u64.to_ne_bytes().to_vec(),
+        vec![5],
+        vec![0xf4; 7],
+    ]
+    .concat();
+    assert_eq!(example.safe_bytes(), &expected[..]);
+}
+
+#[test]
+fn pad_per_field_many() {
+    let mut example = ManyFields {
+        f0: 0,
+        f1: 1,
+        f2: 2,
+        f3: 3,
+        f4: 4,
+        f5: 5,
+        f6: 6,
+        f7: 7,
+        f8: 8,
+        f9: 9,
+        f10: 10,
+        f11: 11,
+        f12: 12,
+        f13: 13,
+        f14: 14,
+        f15: 15,
+    };
+
+    // Marker of field 14 would be the sentinel, so it and later fields use sentinel.
+    let expected = (0..8u8)
+        .flat_map(|i| {
+            let marker = if i * 2 < 14 { 0xf0 | (i * 2) } else { 0xfe };
+            let mut pair = vec![i * 2, marker];
+            pair.extend_from_slice(&u16::from(i * 2 + 1).to_ne_bytes());
+            pair
+        })
+        .collect::<Vec<u8>>();
+    assert_eq!(example.safe_bytes(), &expected[..]);
+    assert_eq!(expected[24..26], [12, 0xfc]);
+    assert_eq!(expected[28..30], [14, 0xfe]);
+}
+
+#[test]
+fn complex_field_types() {
+    let byte = 1u8;
+    let mut example = ComplexFields {
+        flag: 1,
+        ptr: &byte,
+        data: [2, 3],
+        wrapped: Wrapping(4),
+        reference: null_mut(),
+    };
+
+    let ptr = core::mem::size_of::<usize>();
+    let expected = [
+        vec![1],
+        vec![0xfe; ptr - 1],
+        (&byte as *const u8 as usize).to_ne_bytes().to_vec(),
+        2u32.to_ne_bytes().to_vec(),
+        3u32.to_ne_bytes().to_vec(),
+        4u64.to_ne_bytes().to_vec(),
+        0usize.to_ne_bytes().to_vec(),
+    ]
+    .concat();
+    assert_eq!(example.safe_bytes(), &expected[..]);
+}
+
+#[derive(SafeBytes)]
+#[repr(C)]
+struct ZeroSized {
+    a: u8,
+    marker: core::marker::PhantomData<u32>,
+    b: u32,
+    unit: (),
+}
+
+#[test]
+fn zero_sized_fields() {
+    let mut example = ZeroSized {
+        a: 1,
+        marker: core::marker::PhantomData,
+        b: 2,
+        unit: (),
+    };
+    let expected = [vec![1, 0xfe, 0xfe, 0xfe], 2u32.to_ne_bytes().to_vec()].concat();
+    assert_eq!(example.safe_bytes(), &expected[..]);
+}
+
+#[derive(SafeBytes)]
+#[repr(C)]
+struct Empty;
+
+#[derive(SafeBytes)]
+#[repr(C)]
+struct EmptyFields {
+    unit: (),
+    empty: Empty,
+    array: [u64; 0],
+    marker: core::marker::PhantomData<u32>,
+}
+
+#[test]
+fn empty_types() {
+    assert!(Empty.safe_bytes().is_empty());
+
+    let mut fields = EmptyFields {
+        unit: (),
+        empty: Empty,
+        array: [],
+        marker: core::marker::PhantomData,
+    };
+    assert!(fields.safe_bytes().is_empty());
+    assert!(<EmptyFields as PaddingBane>::PADDING_MAP.is_empty());
+
+    assert!(((), Empty).safe_bytes().is_empty());
+    assert!([Empty, Empty].safe_bytes().is_empty());
+}
+
+#[derive(SafeBytes)]
+#[repr(C)]
+struct Borrowed<'a> {
+    tag: u8,
+    _marker: core::marker::PhantomData<&'a ()>,
+    data: u32,
+}
+
+#[derive(SafeBytes)]
+#[repr(C)]
+struct BorrowedGeneric<'a, 'b: 'a, T> {
+    value: T,
+    _marker: core::marker::PhantomData<(&'a (), &'b mut T)>,
+    tag: u8,
+}
+
+fn borrowed_bytes<'a>(value: &'a mut Borrowed<'_>) -> &'a [u8] {
+    value.safe_bytes()
+}
+
+#[test]
+fn lifetime_markers() {
+    let mut borrowed = Borrowed {
+        tag: 1,
+        _marker: core::marker::PhantomData,
+        data: 2,
+    };
+    let expected = [vec![1, 0xfe, 0xfe, 0xfe], 2u32.to_ne_bytes().to_vec()].concat();
+    assert_eq!(borrowed_bytes(&mut borrowed), &expected[..]);
+    assert_eq!(<Borrowed<'static> as PaddingBane>::PADDING_MAP, &[(1, 3)]);
+
+    let mut generic = BorrowedGeneric {
+        value: 3u16,
+        _marker: core::marker::PhantomData,
+        tag: 4,
+    };
+    let expected = [3u16.to_ne_bytes().to_vec(), vec![4, 0xfe]].concat();
+    assert_eq!(generic.safe_bytes(), &expected[..]);
+}
+
+#[test]
+fn matches_expected() {
+    let mut example = Example2::new(1, 2, 3);
+    let expected = example.expected();
+    assert!(example.safe_bytes_matches(&expected));
+    assert!(!example.safe_bytes_matches(&expected[..23]));
+    assert!(!example.safe_bytes_matches(&Example2::new(1, 2, 4).expected()));
+
+    let mut tuple = Tuple(1, 2, 3);
+    assert!(!tuple.safe_bytes_matches(&[]));
+    assert!([0u8; 0].safe_bytes_matches(&[]));
+}
+
+#[derive(SafeBytes)]
+#[repr(C)]
+struct Conditional {
+    a: u8,
+    #[cfg(feature = "alloc")]
+    wide: u64,
+    #[cfg(not(feature = "alloc"))]
+    narrow: u16,
+    #[cfg(any())]
+    never: u32,
+    b: u8,
+}
+
+#[test]
+fn cfg_fields() {
+    #[cfg(feature = "alloc")]
+    let (mut conditional, expected) = (
+        Conditional {
+            a: 1,
+            wide: 2,
+            b: 3,
+        },
+        [
+            vec![1],
+            vec![0xfe; 7],
+            2u64.to_ne_bytes().to_vec(),
+            vec![3],
+            vec![0xfe; 7],
+        ]
+        .concat(),
+    );
+    #[cfg(not(feature = "alloc"))]
+    let (mut conditional, expected) = (
+        Conditional {
+            a: 1,
+            narrow: 2,
+            b: 3,
+        },
+        [vec![1, 0xfe], 2u16.to_ne_bytes().to_vec(), vec![3, 0xfe]].concat(),
+    );
+
+    assert_eq!(conditional.safe_bytes(), &expected[..]);
+    let fields = conditional.get_fields();
+    let offset = &conditional.b as *const u8 as usize - &conditional as *const _ as usize;
+    assert_eq!(Conditional::field(fields, 2).unwrap().offset(), offset);
+    assert!(Conditional::field(fields, 3).is_none());
+}
+
+#[test]
+fn padding_map() {
+    use safe_bytes::PaddingBane;
+
+    assert_eq!(Example2::PADDING_MAP, &[(1, 7), (18, 6)]);
+    assert_eq!(
+        <core::num::Wrapping<Example2>>::PADDING_MAP,
+        Example2::PADDING_MAP
+    );
+    assert_eq!(<ComplexFields<u8>>::PADDING_MAP.len(), 1);
+    assert!(u64::PADDING_MAP.is_empty());
+    assert!(<[Example2; 2]>::PADDING_MAP.is_empty());
+}
+
+#[test]
+fn offsets_match_instance_addresses() {
+    use safe_bytes::{typed_field, PaddingBane};
+
+    let example = ComplexFields::<u16> {
+        flag: 1,
+        ptr: core::ptr::null(),
+        data: [2, 3],
+        wrapped: Wrapping(4),
+        reference: null_mut(),
+    };
+    let derived = example.get_fields();
+    let computed: <ComplexFields<u16> as PaddingBane>::Fields = (
+        typed_field!(example, ComplexFields<u16>, flag),
+        typed_field!(example, ComplexFields<u16>, ptr),
+        typed_field!(example, ComplexFields<u16>, data),
+        typed_field!(example, ComplexFields<u16>, wrapped),
+        typed_field!(example, ComplexFields<u16>, reference),
+    );
+    let pairs = [
+        (derived.0.raw(), computed.0.raw()),
+        (derived.1.raw(), computed.1.raw()),
+        (derived.2.raw(), computed.2.raw()),
+        (derived.3.raw(), computed.3.raw()),
+        (derived.4.raw(), computed.4.raw()),
+    ];
+    for (derived, computed) in &pairs {
+        assert_eq!(derived.offset(), computed.offset());
+        assert_eq!(derived.size(), computed.size());
+    }
+}
+
+#[test]
+fn tuple_struct() {
+    let mut example = Tuple(1, 2, 3);
+    let expected = [
+        vec![1, 0xfe, 0xfe, 0xfe],
+        2u32.to_ne_bytes().to_vec(),
+        3u16.to_ne_bytes().to_vec(),
+        vec![0xfe, 0xfe],
+    ]
+    .concat();
+    assert_eq!(example.safe_bytes(), &expected[..]);
+}
+
+#[test]
+fn prefix() {
+    use safe_bytes::PaddingBane;
+
+    let mut example = Example2::new(1, 2, 3);
+    let expected = example.expected();
+    assert_eq!(example.safe_bytes_prefix(0), &expected[..1]);
+    assert_eq!(example.safe_bytes_prefix(1), &expected[..16]);
+    assert_eq!(example.safe_bytes_prefix(2), &expected[..18]);
+    assert!(Example2::field(example.get_fields(), 3).is_none());
+}
+
+#[test]
+fn field_zeroed() {
+    use safe_bytes::PaddingBane;
+
+    let mut example = Example2::new(1, 2, 3);
+    let mut expected = example.expected();
+    expected[16..18].copy_from_slice(&[0, 0]);
+
+    let bytes = unsafe { example.safe_bytes_with_field_zeroed(2) };
+    assert_eq!(bytes, &expected[..]);
+    assert_eq!(example.c, 0);
+}
+
+#[test]
+fn declaration_order() {
+    let mut example = Reordered {
+        a: 1,
+        b: 2,
+        c: 3,
+        d: Example2::new(4, 5, 6),
+    };
+
+    let mut buffer = [0; Reordered::DECLARATION_ORDER_SIZE];
+    let expected = [
+        vec![1],
+        2u64.to_ne_bytes().to_vec(),
+        3u16.to_ne_bytes().to_vec(),
+        Example2::new(4, 5, 6).expected(),
+    ]
+    .concat();
+    assert_eq!(example.declaration_order_bytes(&mut buffer), &expected[..]);
+    assert_eq!(buffer.len(), 35);
+}
+
+#[test]
+fn patch_bytes() {
+    use safe_bytes::PaddingBane;
+
+    let mut example = Example2::new(1, 2, 3);
+    let bytes = unsafe { example.as_safe_bytes_mut() };
+    assert_eq!(bytes, &Example2::new(1, 2, 3).expected()[..]);
+
+    // Patch `c` field.
+    bytes[16..18].copy_from_slice(&7u16.to_ne_bytes());
+    assert_eq!(example.c, 7);
+}
+
+#[test]
+fn into_uninit() {
+    use {core::mem::MaybeUninit, safe_bytes::PaddingBane};
+
+    let mut example = Example2::new(1, 2, 3);
+    let mut out = [MaybeUninit::<u8>::uninit(); 24];
+    let bytes = example.safe_bytes_into_uninit(&mut out);
+    assert_eq!(bytes, &Example2::new(1, 2, 3).expected()[..]);
+}
+
+#[test]
+#[should_panic]
+fn into_uninit_wrong_length() {
+    use {core::mem::MaybeUninit, safe_bytes::PaddingBane};
+
+    let mut example = Example2::new(1, 2, 3);
+    let mut out = [MaybeUninit::<u8>::uninit(); 16];
+    example.safe_bytes_into_uninit(&mut out);
+}
+
+#[derive(SafeBytes)]
+#[repr(C)]
+struct Flexible {
+    len: u32,
+    tag: u8,
+    data: [u8; 0],
+}
+
+#[test]
+fn trailing_flexible_member() {
+    let mut flexible: Flexible = unsafe { core::mem::zeroed() };
+    flexible.len = 1;
+    flexible.tag = 2;
+
+    // Bytes after zero-sized member are padding, even if zeroed before move.
+    let expected = [1u32.to_ne_bytes().to_vec(), vec![2, 0xfe, 0xfe, 0xfe]].concat();
+    assert_eq!(flexible.safe_bytes(), &expected[..]);
+}
+
+/// Field named like a local of generated code.
+#[derive(SafeBytes)]
+#[repr(C)]
+struct Reserved {
+    kind: u8,
+    padding: [u8; 3],
+    value: u32,
+    flag: u8,
+}
+
+#[test]
+fn field_named_padding() {
+    let mut reserved = Reserved {
+        kind: 1,
+        padding: [0; 3],
+        value: 2,
+        flag: 3,
+    };
+    let expected = [
+        vec![1, 0, 0, 0],
+        2u32.to_ne_bytes().to_vec(),
+        vec![3, 0xfe, 0xfe, 0xfe],
+    ]
+    .concat();
+    assert_eq!(reserved.safe_bytes(), &expected[..]);
+}
+
+/// Field named like a parameter of generated `PaddingBane::field`.
+#[derive(SafeBytes)]
+#[repr(C)]
+struct Indexed {
+    index: u32,
+    flag: u8,
+}
+
+#[test]
+fn field_named_index() {
+    let mut indexed = Indexed { index: 1, flag: 2 };
+    let fields = indexed.get_fields();
+    assert_eq!(Indexed::field(fields, 0), Some(Field::new(0, 4)));
+    assert_eq!(Indexed::field(fields, 1), Some(Field::new(4, 1)));
+    assert_eq!(Indexed::field(fields, 2), None);
+
+    let expected = [1u32.to_ne_bytes().to_vec(), vec![2, 0xfe, 0xfe, 0xfe]].concat();
+    assert_eq!(indexed.safe_bytes(), &expected[..]);
+}
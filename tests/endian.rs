@@ -0,0 +1,206 @@
+//! Exact bytes of derived structs on both endiannesses.
+//! Fields are placed so that layout is the same on 32 and 64 bit targets.
+
+use safe_bytes::SafeBytes;
+
+#[derive(SafeBytes)]
+#[repr(C)]
+struct Small {
+    a: u8,
+    b: u16,
+    c: u32,
+}
+
+#[derive(SafeBytes)]
+#[repr(C, align(8))]
+struct Wide {
+    a: u32,
+    b: u8,
+    c: u64,
+    d: u16,
+}
+
+#[derive(SafeBytes)]
+#[repr(C)]
+struct Nested {
+    small: Small,
+    tag: u8,
+    value: u32,
+}
+
+#[derive(SafeBytes)]
+#[repr(C)]
+struct Tuple(u16, u8, u32);
+
+/// Bytes with explicit byte order are the same on both endiannesses.
+#[derive(SafeBytes)]
+#[safe_bytes(byteorder)]
+#[repr(C)]
+struct Ordered {
+    a: u8,
+    b: u16,
+    c: [u32; 2],
+    d: f32,
+    e: [u8; 2],
+    f: core::primitive::u32,
+}
+
+#[rustfmt::skip]
+mod ordered {
+    pub const LE: [u8; 24] = [
+        0x01, // a
+        0xfe, // pad
+        0x02, 0x01, // b
+        0x04, 0x03, 0x02, 0x01, 0x08, 0x07, 0x06, 0x05, // c
+        0x00, 0x00, 0x80, 0x3f, // d
+        0x09, 0x0a, // e
+        0xfe, 0xfe, // pad
+        0x0e, 0x0d, 0x0c, 0x0b, // f
+    ];
+
+    pub const BE: [u8; 24] = [
+        0x01, // a
+        0xfe, // pad
+        0x01, 0x02, // b
+        0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, // c
+        0x3f, 0x80, 0x00, 0x00, // d
+        0x09, 0x0a, // e
+        0xfe, 0xfe, // pad
+        0x0b, 0x0c, 0x0d, 0x0e, // f
+    ];
+}
+
+#[rustfmt::skip]
+#[cfg(target_endian = "little")]
+mod expected {
+    pub const SMALL: [u8; 8] = [
+        0x01, // a
+        0xfe, // pad
+        0x02, 0x01, // b
+        0x04, 0x03, 0x02, 0x01, // c
+    ];
+
+    pub const WIDE: [u8; 24] = [
+        0x04, 0x03, 0x02, 0x01, // a
+        0x05, // b
+        0xfe, 0xfe, 0xfe, // pad
+        0x08, 0x07, 0x06, 0x05, 0x04, 0x03, 0x02, 0x01, // c
+        0x02, 0x01, // d
+        0xfe, 0xfe, 0xfe, 0xfe, 0xfe, 0xfe, // pad
+    ];
+
+    pub const NESTED: [u8; 16] = [
+        0x01, // small.a
+        0xfe, // small pad
+        0x02, 0x01, // small.b
+        0x04, 0x03, 0x02, 0x01, // small.c
+        0x07, // tag
+        0xfe, 0xfe, 0xfe, // pad
+        0x0b, 0x0a, 0x09, 0x08, // value
+    ];
+
+    pub const TUPLE: [u8; 8] = [
+        0x02, 0x01, // 0
+        0x03, // 1
+        0xfe, // pad
+        0x07, 0x06, 0x05, 0x04, // 2
+    ];
+}
+
+#[rustfmt::skip]
+#[cfg(target_endian = "big")]
+mod expected {
+    pub const SMALL: [u8; 8] = [
+        0x01, // a
+        0xfe, // pad
+        0x01, 0x02, // b
+        0x01, 0x02, 0x03, 0x04, // c
+    ];
+
+    pub const WIDE: [u8; 24] = [
+        0x01, 0x02, 0x03, 0x04, // a
+        0x05, // b
+        0xfe, 0xfe, 0xfe, // pad
+        0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, // c
+        0x01, 0x02, // d
+        0xfe, 0xfe, 0xfe, 0xfe, 0xfe, 0xfe, // pad
+    ];
+
+    pub const NESTED: [u8; 16] = [
+        0x01, // small.a
+        0xfe, // small pad
+        0x01, 0x02, // small.b
+        0x01, 0x02, 0x03, 0x04, // small.c
+        0x07, // tag
+        0xfe, 0xfe, 0xfe, // pad
+        0x08, 0x09, 0x0a, 0x0b, // value
+    ];
+
+    pub const TUPLE: [u8; 8] = [
+        0x01, 0x02, // 0
+        0x03, // 1
+        0xfe, // pad
+        0x04, 0x05, 0x06, 0x07, // 2
+    ];
+}
+
+fn small() -> Small {
+    Small {
+        a: 0x01,
+        b: 0x0102,
+        c: 0x0102_0304,
+    }
+}
+
+#[test]
+fn small_bytes() {
+    assert_eq!(small().safe_bytes(), &expected::SMALL);
+}
+
+#[test]
+fn wide_bytes() {
+    let mut wide = Wide {
+        a: 0x0102_0304,
+        b: 0x05,
+        c: 0x0102_0304_0506_0708,
+        d: 0x0102,
+    };
+    assert_eq!(wide.safe_bytes(), &expected::WIDE);
+}
+
+#[test]
+fn nested_bytes() {
+    let mut nested = Nested {
+        small: small(),
+        tag: 0x07,
+        value: 0x0809_0a0b,
+    };
+    assert_eq!(nested.safe_bytes(), &expected::NESTED);
+}
+
+#[test]
+fn tuple_bytes() {
+    assert_eq!(
+        Tuple(0x0102, 0x03, 0x0405_0607).safe_bytes(),
+        &expected::TUPLE
+    );
+}
+
+#[test]
+fn explicit_byte_order() {
+    let mut ordered = Ordered {
+        a: 0x01,
+        b: 0x0102,
+        c: [0x0102_0304, 0x0506_0708],
+        d: 1.0,
+        e: [0x09, 0x0a],
+        f: 0x0b0c_0d0e,
+    };
+    let mut buffer = [0; 32];
+
+    assert_eq!(ordered.to_le_safe_bytes(&mut buffer), &ordered::LE);
+    assert_eq!(ordered.to_be_safe_bytes(&mut buffer), &ordered::BE);
+
+    // The value itself keeps native byte order.
+    assert_eq!(ordered.b, 0x0102);
+}
@@ -0,0 +1,58 @@
+use {
+    core::mem::{align_of, size_of},
+    safe_bytes::SafeBytes,
+};
+
+#[derive(Clone, Copy, SafeBytes)]
+#[repr(u8)]
+enum Kind {
+    First = 1,
+    Second = 2,
+}
+
+#[derive(Clone, Copy, SafeBytes)]
+#[repr(C)]
+enum Level {
+    Low,
+    High = 0x0102_0304,
+}
+
+#[derive(SafeBytes)]
+#[repr(C)]
+struct Record {
+    kind: Kind,
+    value: u64,
+    level: Level,
+}
+
+#[test]
+fn fieldless_enum() {
+    assert_eq!(Kind::First.safe_bytes(), &[1]);
+    assert_eq!(Kind::Second.safe_bytes(), &[2]);
+    assert_eq!(
+        Level::High.safe_bytes(),
+        &(Level::High as i32).to_ne_bytes()[..]
+    );
+    assert_eq!(Level::Low.safe_bytes(), &0i32.to_ne_bytes()[..]);
+}
+
+#[test]
+fn enum_field() {
+    let mut record = Record {
+        kind: Kind::Second,
+        value: 3,
+        level: Level::High,
+    };
+
+    let gap = align_of::<u64>() - 1;
+    let tail = size_of::<Record>() - 1 - gap - 8 - size_of::<Level>();
+    let expected = [
+        vec![2],
+        vec![0xfe; gap],
+        3u64.to_ne_bytes().to_vec(),
+        (Level::High as i32).to_ne_bytes().to_vec(),
+        vec![0xfe; tail],
+    ]
+    .concat();
+    assert_eq!(record.safe_bytes(), &expected[..]);
+}
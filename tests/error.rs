@@ -0,0 +1,30 @@
+use safe_bytes::SafeBytesError;
+
+#[test]
+fn display() {
+    let messages = [
+        (
+            SafeBytesError::BufferTooSmall { needed: 8, got: 4 },
+            "buffer of 4 bytes is too small, 8 bytes needed",
+        ),
+        (
+            SafeBytesError::FieldOutOfBounds { index: 2 },
+            "field 2 is out of bounds",
+        ),
+        (SafeBytesError::FieldOverlap, "fields overlap"),
+        (
+            SafeBytesError::SizeMismatch,
+            "length of bytes is not equal to the size of the type",
+        ),
+    ];
+    for (error, message) in messages {
+        assert_eq!(error.to_string(), message);
+    }
+}
+
+#[test]
+#[cfg(safe_bytes_core_error)]
+fn error_trait() {
+    let error: Box<dyn std::error::Error> = Box::new(SafeBytesError::FieldOverlap);
+    assert_eq!(error.to_string(), "fields overlap");
+}
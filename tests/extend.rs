@@ -0,0 +1,122 @@
+use safe_bytes::SafeBytes;
+
+/// Type that implements `SafeBytes` directly, without `PaddingBane`.
+struct Packet {
+    header: [u8; 2],
+    payload: [u8; 2],
+}
+
+impl SafeBytes for Packet {
+    fn safe_bytes(&mut self) -> &[u8] {
+        self.header[1] = self.payload.len() as u8;
+        &self.header
+    }
+}
+
+/// Generic local wrapper delegating to wrapped `SafeBytes` implementation.
+struct Framed<T>(T);
+
+impl<T> SafeBytes for Framed<T>
+where
+    T: SafeBytes,
+{
+    fn safe_bytes(&mut self) -> &[u8] {
+        self.0.safe_bytes()
+    }
+}
+
+#[test]
+fn direct_impl() {
+    let mut packet = Packet {
+        header: [1, 0],
+        payload: [3, 4],
+    };
+    assert_eq!(packet.safe_bytes(), &[1, 2]);
+
+    let mut framed = Framed(7u8);
+    assert_eq!(framed.safe_bytes(), &[7]);
+}
+
+mod external {
+    #[repr(C)]
+    pub struct Header {
+        pub kind: u8,
+        pub len: u64,
+        pub flags: u16,
+    }
+}
+
+safe_bytes::impl_padding_bane_struct!(external::Header {
+    kind: u8,
+    len: u64,
+    flags: u16,
+});
+
+#[test]
+fn external_struct() {
+    let mut header = external::Header {
+        kind: 1,
+        len: 2,
+        flags: 3,
+    };
+    let expected = [
+        vec![1],
+        vec![0xfe; 7],
+        2u64.to_ne_bytes().to_vec(),
+        3u16.to_ne_bytes().to_vec(),
+        vec![0xfe; 6],
+    ]
+    .concat();
+    assert_eq!(header.safe_bytes(), &expected[..]);
+    assert_eq!(
+        <external::Header as safe_bytes::PaddingBane>::PADDING_MAP,
+        &[(1, 7), (18, 6)]
+    );
+}
+
+#[repr(transparent)]
+struct MyId<T>(T);
+
+safe_bytes::delegate_padding_bane!(MyId<T> { 0: T });
+
+#[repr(transparent)]
+struct Meters {
+    value: f64,
+}
+
+safe_bytes::delegate_padding_bane!(Meters { value: f64 });
+
+#[test]
+fn delegated_wrapper() {
+    let mut id = MyId(external::Header {
+        kind: 1,
+        len: 2,
+        flags: 3,
+    });
+    let expected = [
+        vec![1],
+        vec![0xfe; 7],
+        2u64.to_ne_bytes().to_vec(),
+        3u16.to_ne_bytes().to_vec(),
+        vec![0xfe; 6],
+    ]
+    .concat();
+    assert_eq!(id.safe_bytes(), &expected[..]);
+    assert_eq!(
+        <MyId<external::Header> as safe_bytes::PaddingBane>::PADDING_MAP,
+        &[(1, 7), (18, 6)]
+    );
+
+    let mut ids = [MyId(1u32), MyId(2)];
+    assert_eq!(
+        ids.safe_bytes(),
+        &[1u32.to_ne_bytes(), 2u32.to_ne_bytes()].concat()[..]
+    );
+
+    let mut meters = Meters { value: 1.5 };
+    assert_eq!(meters.safe_bytes(), &1.5f64.to_ne_bytes()[..]);
+    assert_eq!(
+        <Meters as safe_bytes::StaticFields>::fields(),
+        <f64 as safe_bytes::StaticFields>::fields()
+    );
+}
@@ -0,0 +1,68 @@
+#![cfg(feature = "std")]
+
+mod common;
+
+use {
+    common::Example2,
+    safe_bytes::{read_framed, write_framed, FromSafeBytes, SafeBytes},
+    std::io::{Cursor, ErrorKind},
+};
+
+#[derive(Clone, Copy, Debug, PartialEq, SafeBytes)]
+#[repr(C)]
+struct Sample {
+    id: u8,
+    value: u32,
+}
+
+// Any bytes are valid for integer fields.
+unsafe impl FromSafeBytes for Sample {}
+
+#[test]
+fn round_trip() {
+    let mut first = Sample { id: 1, value: 2 };
+    let mut second = Sample { id: 3, value: 4 };
+
+    let mut cursor = Cursor::new(Vec::new());
+    write_framed(&mut cursor, &mut first).unwrap();
+    write_framed(&mut cursor, &mut second).unwrap();
+
+    let frame = [
+        vec![8, 0, 0, 0],
+        vec![1, 0xfe, 0xfe, 0xfe],
+        2u32.to_ne_bytes().to_vec(),
+    ]
+    .concat();
+    assert_eq!(&cursor.get_ref()[..12], &frame[..]);
+    assert_eq!(cursor.get_ref().len(), 24);
+
+    cursor.set_position(0);
+    assert_eq!(read_framed::<_, Sample>(&mut cursor).unwrap(), first);
+    assert_eq!(read_framed::<_, Sample>(&mut cursor).unwrap(), second);
+    let eof = read_framed::<_, Sample>(&mut cursor).unwrap_err();
+    assert_eq!(eof.kind(), ErrorKind::UnexpectedEof);
+}
+
+#[test]
+fn frame_of_slice() {
+    let mut values = [Example2::new(1, 2, 3), Example2::new(4, 5, 6)];
+    let mut frame = Vec::new();
+    write_framed(&mut frame, &mut values[..]).unwrap();
+
+    let bytes = [
+        Example2::new(1, 2, 3).expected(),
+        Example2::new(4, 5, 6).expected(),
+    ]
+    .concat();
+    assert_eq!(&frame[..4], &(bytes.len() as u32).to_le_bytes());
+    assert_eq!(&frame[4..], &bytes[..]);
+}
+
+#[test]
+fn size_mismatch() {
+    let mut frame = Vec::new();
+    write_framed(&mut frame, &mut 1u16).unwrap();
+
+    let err = read_framed::<_, u32>(&mut &frame[..]).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::InvalidData);
+}
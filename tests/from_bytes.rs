@@ -0,0 +1,50 @@
+mod common;
+
+use {
+    common::Example2,
+    safe_bytes::{assert_safe_bytes_layout, FromSafeBytes, SafeBytes, SafeBytesError},
+};
+
+// Any bytes are valid for `u8`, `u64` and `u16` fields.
+unsafe impl FromSafeBytes for Example2 {}
+
+#[test]
+fn roundtrip() {
+    let mut example = Example2::new(1, 2, 3);
+    let read = Example2::from_safe_bytes(example.safe_bytes()).unwrap();
+    assert_eq!((read.a, read.b, read.c), (1, 2, 3));
+
+    let mut array = [0x0102_0304u32, 5];
+    assert_eq!(<[u32; 2]>::from_safe_bytes(array.safe_bytes()), Ok(array));
+}
+
+#[test]
+fn wrong_length() {
+    assert_eq!(
+        u32::from_safe_bytes(&[1, 2, 3]),
+        Err(SafeBytesError::SizeMismatch)
+    );
+    assert_eq!(
+        u32::from_safe_bytes(&[1, 2, 3, 4, 5]),
+        Err(SafeBytesError::SizeMismatch)
+    );
+}
+
+#[derive(Clone, Copy, SafeBytes)]
+#[safe_bytes(pad_per_field)]
+#[repr(C)]
+struct Nested {
+    tag: u16,
+    inner: Example2,
+    tail: [u8; 3],
+}
+
+unsafe impl FromSafeBytes for Nested {}
+
+#[test]
+fn layout_assertion() {
+    assert_safe_bytes_layout!(Example2);
+    assert_safe_bytes_layout!(Nested);
+    assert_safe_bytes_layout!([Nested; 3]);
+    assert_safe_bytes_layout!(u64);
+}
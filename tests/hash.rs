@@ -0,0 +1,54 @@
+mod common;
+
+use {
+    common::Example2,
+    core::{hash::Hasher, mem::MaybeUninit, ptr::addr_of_mut},
+    safe_bytes::PaddingBane,
+    std::collections::hash_map::DefaultHasher,
+};
+
+/// Creates value with padding bytes set to `garbage`.
+fn with_garbage(garbage: u8, a: u8, b: u64, c: u16) -> Example2 {
+    let mut value = MaybeUninit::<Example2>::uninit();
+    unsafe {
+        value.as_mut_ptr().write_bytes(garbage, 1);
+        let ptr = value.as_mut_ptr();
+        addr_of_mut!((*ptr).a).write(a);
+        addr_of_mut!((*ptr).b).write(b);
+        addr_of_mut!((*ptr).c).write(c);
+        value.assume_init()
+    }
+}
+
+fn hash(value: &mut Example2) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    value.hash_safe_bytes(&mut hasher);
+    hasher.finish()
+}
+
+#[test]
+fn equal_values_hash_equally() {
+    let mut zeroed = with_garbage(0x00, 1, 2, 3);
+    let mut filled = with_garbage(0xff, 1, 2, 3);
+    assert_eq!(hash(&mut zeroed), hash(&mut filled));
+
+    let mut other = with_garbage(0x00, 1, 2, 4);
+    assert_ne!(hash(&mut zeroed), hash(&mut other));
+}
+
+#[test]
+fn array_hash() {
+    let mut examples = [Example2::new(1, 2, 3), Example2::new(4, 5, 6)];
+    let mut hasher = DefaultHasher::new();
+    examples.hash_safe_bytes(&mut hasher);
+
+    let mut expected = DefaultHasher::new();
+    expected.write(
+        &[
+            Example2::new(1, 2, 3).expected(),
+            Example2::new(4, 5, 6).expected(),
+        ]
+        .concat(),
+    );
+    assert_eq!(hasher.finish(), expected.finish());
+}
@@ -0,0 +1,112 @@
+#![cfg(feature = "heapless")]
+
+mod common;
+
+use {
+    common::Example2,
+    core::mem::MaybeUninit,
+    heapless::Vec,
+    safe_bytes::{PaddingBane, SafeBytes, StaticFields},
+};
+
+fn buffer_offset<V, T>(vec: &V, buffer: *const T) -> usize {
+    buffer as usize - vec as *const V as usize
+}
+
+#[test]
+fn unused_capacity() {
+    let mut vec = Vec::<u8, 16>::from_slice(&[1, 2, 3]).unwrap();
+    let offset = buffer_offset(&vec, vec.as_ptr());
+
+    let bytes = vec.safe_bytes();
+    assert_eq!(bytes.len(), 24);
+    assert_eq!(&bytes[offset..offset + 3], &[1, 2, 3]);
+    assert_eq!(&bytes[offset + 3..offset + 16], &[0xfe; 13]);
+}
+
+#[test]
+fn padded_elements() {
+    let mut vec = Vec::<Example2, 3>::new();
+    vec.push(Example2::new(1, 2, 3)).ok().unwrap();
+    vec.push(Example2::new(4, 5, 6)).ok().unwrap();
+    let offset = buffer_offset(&vec, vec.as_ptr());
+
+    let expected = [
+        Example2::new(1, 2, 3).expected(),
+        Example2::new(4, 5, 6).expected(),
+        vec![0xfe; 24],
+    ]
+    .concat();
+    assert_eq!(&vec.safe_bytes()[offset..offset + 72], &expected[..]);
+}
+
+#[test]
+fn short_length() {
+    let mut vec = Vec::<u16, 4, u16>::from_slice(&[7]).unwrap();
+    let offset = buffer_offset(&vec, vec.as_ptr());
+
+    let expected = [7u16.to_ne_bytes().to_vec(), vec![0xfe; 6]].concat();
+    assert_eq!(&vec.safe_bytes()[offset..offset + 8], &expected[..]);
+}
+
+#[test]
+fn mixed_lengths() {
+    let mut vecs = [
+        Vec::<bool, 4, u32>::new(),
+        Vec::<bool, 4, u32>::from_slice(&[true, false, true]).unwrap(),
+    ];
+    let offset = buffer_offset(&vecs[0], vecs[0].as_ptr());
+
+    let bytes = vecs.safe_bytes();
+    assert_eq!(&bytes[offset..offset + 4], &[0xfe; 4]);
+    assert_eq!(&bytes[8 + offset..8 + offset + 4], &[1, 0, 1, 0xfe]);
+
+    let mut elements = [
+        Vec::<Example2, 2>::from_slice(&[Example2::new(1, 2, 3)]).unwrap(),
+        Vec::<Example2, 2>::new(),
+        Vec::<Example2, 2>::from_slice(&[Example2::new(4, 5, 6), Example2::new(7, 8, 9)]).unwrap(),
+    ];
+    let size = core::mem::size_of::<Vec<Example2, 2>>();
+    let offset = buffer_offset(&elements[0], elements[0].as_ptr());
+
+    let expected = [
+        Example2::new(4, 5, 6).expected(),
+        Example2::new(7, 8, 9).expected(),
+    ]
+    .concat();
+    let bytes = elements.safe_bytes();
+    assert_eq!(
+        &bytes[size + offset..size + offset + 48],
+        &vec![0xfe; 48][..]
+    );
+    assert_eq!(
+        &bytes[2 * size + offset..2 * size + offset + 48],
+        &expected[..]
+    );
+}
+
+#[derive(SafeBytes)]
+#[repr(C)]
+struct Packet {
+    kind: u32,
+    items: Vec<u16, 4, u16>,
+}
+
+#[test]
+fn static_fields() {
+    let empty = Vec::<Example2, 2>::new();
+    let full =
+        Vec::<Example2, 2>::from_slice(&[Example2::new(1, 2, 3), Example2::new(4, 5, 6)]).unwrap();
+    assert_eq!(empty.get_fields(), Vec::<Example2, 2>::fields());
+    assert!(<Vec<Example2, 2> as PaddingBane>::PADDING_MAP.is_empty());
+    assert_eq!(full.get_fields(), Vec::<Example2, 2>::fields());
+
+    // Length of the vector not yet written is read from sentinel bytes.
+    let mut packet = MaybeUninit::<Packet>::uninit();
+    let bytes = unsafe {
+        core::ptr::addr_of_mut!((*packet.as_mut_ptr()).kind).write(7);
+        Packet::safe_bytes_partial(&mut packet, 1)
+    };
+    let expected = [7u32.to_ne_bytes().to_vec(), vec![0xfe; 12]].concat();
+    assert_eq!(bytes, &expected[..]);
+}
@@ -0,0 +1,94 @@
+use {
+    core::mem::MaybeUninit,
+    safe_bytes::{fill_padding, Field, PaddingBane, SafeBytes, SafeBytesError},
+};
+
+#[test]
+fn fill_valid_layout() {
+    let mut bytes = [MaybeUninit::new(0u8); 8];
+    let mut fields = [Field::new(4, 2), Field::new(0, 1), Field::new(4, 0)];
+    assert_eq!(fill_padding(&mut fields, &mut bytes), Ok(5));
+
+    let bytes = bytes.map(|b| unsafe { b.assume_init() });
+    assert_eq!(bytes, [0, 0xfe, 0xfe, 0xfe, 0, 0, 0xfe, 0xfe]);
+}
+
+#[test]
+fn reject_invalid_layout() {
+    let mut bytes = [MaybeUninit::new(0u8); 8];
+
+    let mut fields = [Field::new(6, 4), Field::new(0, 4)];
+    assert_eq!(
+        fill_padding(&mut fields, &mut bytes),
+        Err(SafeBytesError::FieldOutOfBounds { index: 0 })
+    );
+
+    let mut fields = [Field::new(0, 1), Field::new(usize::MAX, 2)];
+    assert_eq!(
+        fill_padding(&mut fields, &mut bytes),
+        Err(SafeBytesError::FieldOutOfBounds { index: 1 })
+    );
+
+    let mut fields = [Field::new(0, 4), Field::new(2, 4)];
+    assert_eq!(
+        fill_padding(&mut fields, &mut bytes),
+        Err(SafeBytesError::FieldOverlap)
+    );
+
+    // Bytes are left untouched.
+    assert!(bytes.iter().all(|b| unsafe { b.assume_init() } == 0));
+}
+
+#[test]
+fn fill_every_length() {
+    for len in 0..100 {
+        // Guard bytes around filled range.
+        let mut bytes = vec![MaybeUninit::new(0u8); len + 2];
+        let mut fields = [];
+        let count = fill_padding(&mut fields, &mut bytes[1..len + 1]);
+        assert_eq!(count, Ok(len));
+
+        let bytes = bytes
+            .iter()
+            .map(|b| unsafe { b.assume_init() })
+            .collect::<Vec<_>>();
+        assert_eq!(bytes[0], 0);
+        assert!(bytes[1..len + 1].iter().all(|&b| b == 0xfe));
+        assert_eq!(bytes[len + 1], 0);
+    }
+}
+
+#[derive(SafeBytes)]
+#[safe_bytes(layout_const)]
+#[repr(C)]
+struct Described<T> {
+    tag: u8,
+    value: T,
+    pair: (u16, u8),
+}
+
+#[derive(SafeBytes)]
+#[safe_bytes(layout_const)]
+#[repr(C)]
+struct DescribedTuple(u16, u64);
+
+#[test]
+fn layout_const() {
+    assert_eq!(
+        Described::<u32>::LAYOUT,
+        &[("tag", 0, 1), ("value", 4, 4), ("pair", 8, 4)]
+    );
+    assert_eq!(Described::<u8>::LAYOUT[1], ("value", 1, 1));
+    assert_eq!(DescribedTuple::LAYOUT[0], ("0", 0, 2));
+
+    let described = Described {
+        tag: 1,
+        value: 2u64,
+        pair: (3, 4),
+    };
+    let fields = described.get_fields();
+    for (index, &(_, offset, size)) in Described::<u64>::LAYOUT.iter().enumerate() {
+        let field = Described::<u64>::field(fields, index).unwrap();
+        assert_eq!((field.offset(), field.size()), (offset, size));
+    }
+}
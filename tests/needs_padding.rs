@@ -0,0 +1,146 @@
+mod common;
+
+use {
+    common::Example2,
+    core::{
+        cell::{Cell, UnsafeCell},
+        cmp::Reverse,
+        mem::{ManuallyDrop, MaybeUninit},
+        num::Wrapping,
+    },
+    safe_bytes::{FromSafeBytes, PaddingBane, SafeBytes},
+};
+
+#[derive(Clone, Copy, SafeBytes)]
+#[repr(C)]
+struct Dense {
+    a: u32,
+    b: [u16; 2],
+    c: Wrapping<u64>,
+}
+
+// Any bytes are valid for integer fields.
+unsafe impl FromSafeBytes for Dense {}
+
+#[derive(SafeBytes)]
+#[repr(C)]
+struct DensePadded {
+    dense: Dense,
+    padded: Example2,
+}
+
+#[derive(SafeBytes)]
+#[repr(C)]
+struct DenseOpaque {
+    a: u64,
+    #[safe_bytes(opaque)]
+    dense: Dense,
+}
+
+#[derive(SafeBytes)]
+#[repr(C)]
+struct Checked<T> {
+    #[safe_bytes(deny_padding)]
+    dense: Dense,
+    #[safe_bytes(deny_padding)]
+    value: T,
+    tag: u8,
+}
+
+fn needs_padding<T: PaddingBane>() -> bool {
+    T::NEEDS_PADDING
+}
+
+#[test]
+fn padding_presence() {
+    assert!(!needs_padding::<u32>());
+    assert!(!needs_padding::<Dense>());
+    assert!(!needs_padding::<[Dense; 4]>());
+    assert!(!needs_padding::<(u32, u32)>());
+    assert!(!needs_padding::<[Example2; 0]>());
+
+    assert!(needs_padding::<Example2>());
+    assert!(needs_padding::<[Example2; 2]>());
+    assert!(needs_padding::<(u8, u32)>());
+    assert!(needs_padding::<DensePadded>());
+    assert!(needs_padding::<DenseOpaque>());
+    assert!(needs_padding::<MaybeUninit<u32>>());
+}
+
+#[test]
+fn wrappers_forward_padding() {
+    assert!(!needs_padding::<Wrapping<u32>>());
+    #[cfg(safe_bytes_saturating)]
+    assert!(!needs_padding::<core::num::Saturating<u32>>());
+    assert!(!needs_padding::<Reverse<Dense>>());
+    assert!(!needs_padding::<ManuallyDrop<[u16; 3]>>());
+    assert!(!needs_padding::<Cell<Dense>>());
+    assert!(!needs_padding::<UnsafeCell<u64>>());
+
+    assert!(needs_padding::<Wrapping<Example2>>());
+    #[cfg(safe_bytes_saturating)]
+    assert!(needs_padding::<core::num::Saturating<(u8, u32)>>());
+    assert!(needs_padding::<Reverse<Example2>>());
+    assert!(needs_padding::<ManuallyDrop<Example2>>());
+    assert!(needs_padding::<Cell<Example2>>());
+    assert!(needs_padding::<UnsafeCell<Example2>>());
+}
+
+#[test]
+fn dense_bytes() {
+    let dense = Dense {
+        a: 1,
+        b: [2, 3],
+        c: Wrapping(4),
+    };
+    let expected = [
+        1u32.to_ne_bytes().to_vec(),
+        2u16.to_ne_bytes().to_vec(),
+        3u16.to_ne_bytes().to_vec(),
+        4u64.to_ne_bytes().to_vec(),
+    ]
+    .concat();
+    let mut copy = dense;
+    assert_eq!(copy.safe_bytes(), &expected[..]);
+
+    let mut slice = [dense; 3];
+    assert_eq!(slice[..].safe_bytes(), &expected.repeat(3)[..]);
+
+    let mut padded = DensePadded {
+        dense,
+        padded: Example2::new(5, 6, 7),
+    };
+    let expected_padded = [expected.clone(), Example2::new(5, 6, 7).expected()].concat();
+    assert_eq!(padded.safe_bytes(), &expected_padded[..]);
+
+    let mut opaque = DenseOpaque { a: 8, dense };
+    let expected_opaque = [8u64.to_ne_bytes().to_vec(), vec![0xfe; 16]].concat();
+    assert_eq!(opaque.safe_bytes(), &expected_opaque[..]);
+}
+
+#[test]
+fn denied_padding() {
+    let dense = Dense {
+        a: 1,
+        b: [2, 3],
+        c: Wrapping(4),
+    };
+    let mut checked = Checked {
+        dense,
+        value: [5u16; 2],
+        tag: 6,
+    };
+    let expected = [
+        1u32.to_ne_bytes().to_vec(),
+        2u16.to_ne_bytes().to_vec(),
+        3u16.to_ne_bytes().to_vec(),
+        4u64.to_ne_bytes().to_vec(),
+        5u16.to_ne_bytes().to_vec(),
+        5u16.to_ne_bytes().to_vec(),
+        vec![6],
+        vec![0xfe; 3],
+    ]
+    .concat();
+    assert_eq!(checked.safe_bytes(), &expected[..]);
+    assert!(needs_padding::<Checked<u32>>());
+}
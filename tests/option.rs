@@ -0,0 +1,94 @@
+use {
+    core::num::{NonZeroI16, NonZeroU32},
+    safe_bytes::SafeBytes,
+};
+
+#[derive(SafeBytes)]
+#[repr(C)]
+struct OptionalRefs<'a> {
+    tag: u8,
+    value: Option<&'a u32>,
+    out: Option<&'a mut u64>,
+}
+
+const PTR: usize = core::mem::size_of::<usize>();
+
+#[test]
+fn none_refs() {
+    let mut example = OptionalRefs {
+        tag: 1,
+        value: None,
+        out: None,
+    };
+    let expected = [vec![1], vec![0xfe; PTR - 1], vec![0; PTR * 2]].concat();
+    assert_eq!(example.safe_bytes(), &expected[..]);
+}
+
+#[test]
+fn some_refs() {
+    let value = 2u32;
+    let mut out = 3u64;
+    let value_address = &value as *const u32 as usize;
+    let out_address = &mut out as *mut u64 as usize;
+
+    let mut example = OptionalRefs {
+        tag: 1,
+        value: Some(&value),
+        out: Some(&mut out),
+    };
+    let expected = [
+        vec![1],
+        vec![0xfe; PTR - 1],
+        value_address.to_ne_bytes().to_vec(),
+        out_address.to_ne_bytes().to_vec(),
+    ]
+    .concat();
+    assert_eq!(example.safe_bytes(), &expected[..]);
+}
+
+#[derive(SafeBytes)]
+#[repr(C)]
+struct Niches {
+    flag: Option<bool>,
+    letter: Option<char>,
+}
+
+#[test]
+fn niche_options() {
+    let mut some = Niches {
+        flag: Some(true),
+        letter: Some('x'),
+    };
+    let expected = [
+        vec![1, 0xfe, 0xfe, 0xfe],
+        ('x' as u32).to_ne_bytes().to_vec(),
+    ]
+    .concat();
+    assert_eq!(some.safe_bytes(), &expected[..]);
+
+    // Niche values of `None` are unspecified, but all bytes are initialized.
+    let mut none = Niches {
+        flag: None,
+        letter: None,
+    };
+    let bytes = none.safe_bytes();
+    assert_eq!(bytes.len(), 8);
+    assert_eq!(&bytes[1..4], &[0xfe; 3]);
+    assert_ne!(bytes[0], 0);
+    assert_ne!(bytes[0], 1);
+}
+
+#[test]
+fn non_zero_option_array() {
+    let mut ids = [1, 0, u32::MAX, 0].map(NonZeroU32::new);
+    assert_eq!(ids[1], None);
+    let expected = [1u32.to_ne_bytes(), [0; 4], u32::MAX.to_ne_bytes(), [0; 4]].concat();
+    assert_eq!(ids.safe_bytes(), &expected[..]);
+
+    let mut none = [None::<NonZeroU32>; 3];
+    assert_eq!(none.safe_bytes(), &[0; 12]);
+
+    let mut deltas = [NonZeroI16::new(-2), None, NonZeroI16::new(0x0102)];
+    let expected = [(-2i16).to_ne_bytes(), [0; 2], 0x0102i16.to_ne_bytes()].concat();
+    assert_eq!(deltas[..].safe_bytes(), &expected[..]);
+}
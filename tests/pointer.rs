@@ -0,0 +1,179 @@
+use {
+    core::{
+        ffi::c_void,
+        ptr::{null, null_mut, NonNull},
+        sync::atomic::AtomicPtr,
+    },
+    safe_bytes::SafeBytes,
+};
+
+/// Newtype over pointer, as found in many crates.
+#[derive(SafeBytes)]
+#[repr(transparent)]
+struct Handle(NonNull<u32>);
+
+#[derive(SafeBytes)]
+#[repr(C)]
+struct Pointers {
+    tag: u8,
+    mut_ptr: *mut u32,
+    const_ptr: *const u32,
+    non_null: NonNull<u32>,
+    option_non_null: Option<NonNull<u32>>,
+    atomic: AtomicPtr<u32>,
+    handle: Handle,
+}
+
+#[test]
+fn pointers() {
+    let mut value = 1u32;
+    let address = &mut value as *mut u32 as usize;
+    let non_null = NonNull::from(&mut value);
+
+    let mut example = Pointers {
+        tag: 2,
+        mut_ptr: null_mut(),
+        const_ptr: null(),
+        non_null,
+        option_non_null: Some(non_null),
+        atomic: AtomicPtr::new(non_null.as_ptr()),
+        handle: Handle(non_null),
+    };
+
+    let ptr = core::mem::size_of::<usize>();
+    let expected = [
+        vec![2],
+        vec![0xfe; ptr - 1],
+        0usize.to_ne_bytes().to_vec(),
+        0usize.to_ne_bytes().to_vec(),
+        address.to_ne_bytes().to_vec(),
+        address.to_ne_bytes().to_vec(),
+        address.to_ne_bytes().to_vec(),
+        address.to_ne_bytes().to_vec(),
+    ]
+    .concat();
+    assert_eq!(example.safe_bytes(), &expected[..]);
+}
+
+/// Borrowed buffer passed along with its length.
+#[derive(SafeBytes)]
+#[repr(C)]
+struct Buffer {
+    tag: u8,
+    data: *const [u8],
+    scratch: NonNull<[u16]>,
+}
+
+#[test]
+fn slice_pointers() {
+    let data = [1u8, 2, 3];
+    let mut scratch = [4u16, 5];
+
+    let mut buffer = Buffer {
+        tag: 6,
+        data: &data[..],
+        scratch: NonNull::from(&mut scratch[..]),
+    };
+
+    // Order of address and length in slice pointer is unspecified.
+    let data_words: [usize; 2] = unsafe { core::mem::transmute(buffer.data) };
+    let scratch_words: [usize; 2] = unsafe { core::mem::transmute(buffer.scratch) };
+    assert!(data_words.contains(&3));
+    assert!(scratch_words.contains(&2));
+
+    let ptr = core::mem::size_of::<usize>();
+    let expected = [
+        vec![6],
+        vec![0xfe; ptr - 1],
+        data_words[0].to_ne_bytes().to_vec(),
+        data_words[1].to_ne_bytes().to_vec(),
+        scratch_words[0].to_ne_bytes().to_vec(),
+        scratch_words[1].to_ne_bytes().to_vec(),
+    ]
+    .concat();
+    assert_eq!(buffer.safe_bytes(), &expected[..]);
+}
+
+/// Opaque handle of a C library.
+#[derive(SafeBytes)]
+#[repr(transparent)]
+struct OpaqueHandle(*mut c_void);
+
+#[derive(SafeBytes)]
+#[repr(C)]
+struct Session {
+    id: u16,
+    handle: OpaqueHandle,
+    flags: u8,
+}
+
+#[test]
+fn opaque_handle() {
+    let mut value = 1u32;
+    let address = &mut value as *mut u32 as usize;
+
+    let mut session = Session {
+        id: 3,
+        handle: OpaqueHandle(&mut value as *mut u32 as *mut c_void),
+        flags: 4,
+    };
+
+    let ptr = core::mem::size_of::<usize>();
+    let expected = [
+        3u16.to_ne_bytes().to_vec(),
+        vec![0xfe; ptr - 2],
+        address.to_ne_bytes().to_vec(),
+        vec![4],
+        vec![0xfe; ptr - 1],
+    ]
+    .concat();
+    assert_eq!(session.safe_bytes(), &expected[..]);
+}
+
+extern "C" fn double(value: u32) -> u32 {
+    value * 2
+}
+
+/// Table of optional callbacks, as passed over FFI.
+#[derive(SafeBytes)]
+#[repr(C)]
+struct Callbacks {
+    tag: u8,
+    on_value: Option<extern "C" fn(u32) -> u32>,
+    on_reset: Option<unsafe fn()>,
+    required: fn(u8, u16, u32, u64, usize, i8) -> bool,
+}
+
+fn required(_: u8, _: u16, _: u32, _: u64, _: usize, _: i8) -> bool {
+    true
+}
+
+// Casting the same function twice may give different addresses,
+// so expected bytes are taken from stored pointers.
+#[test]
+fn function_pointers() {
+    let mut callbacks = Callbacks {
+        tag: 1,
+        on_value: None,
+        on_reset: None,
+        required,
+    };
+
+    let ptr = core::mem::size_of::<usize>();
+    let expected = [
+        vec![1],
+        vec![0xfe; ptr - 1],
+        0usize.to_ne_bytes().to_vec(),
+        0usize.to_ne_bytes().to_vec(),
+        (callbacks.required as usize).to_ne_bytes().to_vec(),
+    ]
+    .concat();
+    assert_eq!(callbacks.safe_bytes(), &expected[..]);
+
+    callbacks.on_value = Some(double);
+    let address = callbacks.on_value.unwrap() as usize;
+    assert_eq!(
+        &callbacks.safe_bytes()[ptr..2 * ptr],
+        &address.to_ne_bytes()[..]
+    );
+}
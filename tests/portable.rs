@@ -0,0 +1,61 @@
+use safe_bytes::{PortableBytes, SafeBytes};
+
+#[derive(SafeBytes)]
+#[safe_bytes(portable)]
+#[repr(C)]
+struct Inner {
+    flag: bool,
+    value: u32,
+}
+
+#[derive(SafeBytes)]
+#[safe_bytes(portable)]
+struct Message {
+    kind: u8,
+    len: u64,
+    inner: [Inner; 2],
+    ratio: f32,
+}
+
+#[test]
+fn portable_bytes() {
+    let message = Message {
+        kind: 1,
+        len: 0x0203,
+        inner: [
+            Inner {
+                flag: true,
+                value: 0x0405_0607,
+            },
+            Inner {
+                flag: false,
+                value: 8,
+            },
+        ],
+        ratio: 1.0,
+    };
+
+    // Same bytes on little- and big-endian targets.
+    let expected = [
+        1, // kind
+        0x03, 0x02, 0, 0, 0, 0, 0, 0, // len
+        1, 0x07, 0x06, 0x05, 0x04, // inner[0]
+        0, 8, 0, 0, 0, // inner[1]
+        0, 0, 0x80, 0x3f, // ratio
+    ];
+    assert_eq!(Message::PORTABLE_SIZE, expected.len());
+
+    let mut buffer = [0; 32];
+    assert_eq!(message.portable_bytes(&mut buffer), &expected[..]);
+}
+
+#[test]
+#[should_panic]
+fn portable_buffer_too_small() {
+    let mut buffer = [0; 4];
+    Inner {
+        flag: true,
+        value: 1,
+    }
+    .portable_bytes(&mut buffer);
+}
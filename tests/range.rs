@@ -0,0 +1,84 @@
+mod common;
+
+use {
+    common::Example2,
+    core::ops::{RangeFrom, RangeFull, RangeInclusive, RangeTo},
+    safe_bytes::{PaddingBane, SafeBytes},
+};
+
+#[derive(SafeBytes)]
+#[repr(C)]
+struct Ranges {
+    tag: u8,
+    from: RangeFrom<u32>,
+    to: RangeTo<u16>,
+    full: RangeFull,
+    nested: RangeFrom<Example2>,
+}
+
+#[test]
+fn range_fields() {
+    let mut ranges = Ranges {
+        tag: 1,
+        from: 2..,
+        to: ..3,
+        full: ..,
+        nested: Example2::new(4, 5, 6)..,
+    };
+    let expected = [
+        vec![1],
+        vec![0xfe; 3],
+        2u32.to_ne_bytes().to_vec(),
+        3u16.to_ne_bytes().to_vec(),
+        vec![0xfe; 6],
+        Example2::new(4, 5, 6).expected(),
+    ]
+    .concat();
+    assert_eq!(ranges.safe_bytes(), &expected[..]);
+    assert_eq!(<RangeFrom<Example2>>::PADDING_MAP, &[]);
+    assert_eq!((..).safe_bytes(), &[]);
+}
+
+/// `RangeInclusive` can't implement `PaddingBane`,
+/// its state is stored in own struct instead.
+#[derive(SafeBytes)]
+#[repr(C)]
+struct Inclusive {
+    start: u32,
+    end: u32,
+    empty: bool,
+}
+
+impl From<&RangeInclusive<u32>> for Inclusive {
+    fn from(range: &RangeInclusive<u32>) -> Self {
+        Inclusive {
+            start: *range.start(),
+            end: *range.end(),
+            empty: range.is_empty(),
+        }
+    }
+}
+
+#[test]
+fn inclusive_range_state() {
+    let mut range = 1..=2u32;
+    let bytes = |range: &RangeInclusive<u32>| Inclusive::from(range).safe_bytes().to_vec();
+    let expected = |start: u32, empty: u8| {
+        [
+            start.to_ne_bytes().to_vec(),
+            2u32.to_ne_bytes().to_vec(),
+            vec![empty, 0xfe, 0xfe, 0xfe],
+        ]
+        .concat()
+    };
+
+    assert_eq!(bytes(&range), expected(1, 0));
+    assert_eq!(bytes(&range), bytes(&(1..=2)));
+
+    range.next();
+    assert_eq!(bytes(&range), expected(2, 0));
+
+    // Exhausted range keeps its bounds and differs only in the flag.
+    range.next();
+    assert_eq!(bytes(&range), expected(2, 1));
+}
@@ -0,0 +1,25 @@
+mod common;
+
+use {common::Example2, safe_bytes::SafeBytes};
+
+fn bytes_of<S: SafeBytes + ?Sized>(value: &mut S) -> Vec<u8> {
+    value.safe_bytes().to_vec()
+}
+
+fn bytes_of_nested(value: &mut &mut Example2) -> Vec<u8> {
+    bytes_of(&mut **value)
+}
+
+#[test]
+fn through_mut_reference() {
+    let mut example = Example2::new(1, 2, 3);
+    let expected = example.expected();
+
+    let reference: &mut Example2 = &mut example;
+    assert_eq!(reference.safe_bytes(), &expected[..]);
+
+    assert_eq!(bytes_of(&mut *reference), expected);
+
+    let mut outer = reference;
+    assert_eq!(bytes_of_nested(&mut outer), expected);
+}
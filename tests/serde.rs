@@ -0,0 +1,41 @@
+#![cfg(feature = "serde")]
+
+mod common;
+
+use {
+    common::Example2,
+    safe_bytes::{FromSafeBytes, SafeBytesSer},
+};
+
+// Any bytes are valid for `u8`, `u64` and `u16` fields.
+unsafe impl FromSafeBytes for Example2 {}
+
+#[test]
+fn bincode_roundtrip() {
+    let example = Example2::new(1, 2, 3);
+    let encoded = bincode::serialize(&SafeBytesSer(example)).unwrap();
+    assert_eq!(&encoded[8..], &example.expected()[..]);
+
+    let SafeBytesSer(decoded): SafeBytesSer<Example2> = bincode::deserialize(&encoded).unwrap();
+    assert_eq!((decoded.a, decoded.b, decoded.c), (1, 2, 3));
+}
+
+#[test]
+fn bincode_wrong_length() {
+    let encoded = bincode::serialize(&SafeBytesSer(7u32)).unwrap();
+    assert!(bincode::deserialize::<SafeBytesSer<u64>>(&encoded).is_err());
+}
+
+#[test]
+fn json_roundtrip() {
+    let json = serde_json::to_string(&SafeBytesSer(0x0102u16)).unwrap();
+    assert_eq!(
+        json,
+        format!("{:?}", 0x0102u16.to_ne_bytes()).replace(' ', "")
+    );
+
+    let decoded: SafeBytesSer<u16> = serde_json::from_str(&json).unwrap();
+    assert_eq!(decoded, SafeBytesSer(0x0102));
+    assert!(serde_json::from_str::<SafeBytesSer<u16>>("[1]").is_err());
+    assert!(serde_json::from_str::<SafeBytesSer<u16>>("[1,2,3]").is_err());
+}
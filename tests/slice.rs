@@ -0,0 +1,30 @@
+mod common;
+
+use {common::Example2, safe_bytes::SafeBytes};
+
+#[test]
+fn bool_slice() {
+    let mut flags = [true, false, false, true];
+    let slice: &mut [bool] = &mut flags[..];
+    assert_eq!(slice.safe_bytes(), &[1, 0, 0, 1]);
+
+    let empty: &mut [bool] = &mut [];
+    assert_eq!(empty.safe_bytes(), &[] as &[u8]);
+}
+
+#[test]
+fn bool_array_slice() {
+    let mut flags = [[true, false], [false, true]];
+    let slice: &mut [[bool; 2]] = &mut flags[..];
+    assert_eq!(slice.safe_bytes(), &[1, 0, 0, 1]);
+}
+
+#[test]
+fn padded_slice() {
+    let mut elements = [Example2::new(1, 2, 3), Example2::new(4, 5, 6)];
+    let expected = elements
+        .iter()
+        .flat_map(Example2::expected)
+        .collect::<Vec<_>>();
+    assert_eq!(elements[..].safe_bytes(), &expected[..]);
+}
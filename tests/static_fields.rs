@@ -0,0 +1,105 @@
+mod common;
+
+use {
+    common::Example2,
+    core::{
+        mem::MaybeUninit,
+        num::Wrapping,
+        ptr::{addr_of_mut, write},
+    },
+    safe_bytes::{PaddingBane, SafeBytes, StaticFields},
+};
+
+#[derive(SafeBytes)]
+#[repr(C)]
+struct Nested<T> {
+    a: u8,
+    inner: [Example2; 2],
+    tuple: (u16, Wrapping<u64>),
+    value: T,
+    scratch: MaybeUninit<[u8; 3]>,
+}
+
+fn assert_static<T>(value: &T)
+where
+    T: StaticFields,
+    T::Fields: PartialEq + core::fmt::Debug,
+{
+    assert_eq!(T::fields(), value.get_fields());
+}
+
+#[test]
+fn fields_without_instance() {
+    let nested = Nested {
+        a: 1,
+        inner: [Example2::new(2, 3, 4), Example2::new(5, 6, 7)],
+        tuple: (8, Wrapping(9)),
+        value: 10u32,
+        scratch: MaybeUninit::uninit(),
+    };
+    assert_static(&nested);
+    assert_static(&Example2::new(1, 2, 3));
+    assert_static(&[0u8; 0]);
+    assert_static(&(1u8, [Example2::new(1, 2, 3)]));
+
+    let fields = <Nested<u32>>::fields();
+    assert_eq!(Nested::<u32>::field(fields, 1).unwrap().offset(), 8);
+}
+
+#[test]
+fn partial_bytes() {
+    let mut partial = MaybeUninit::<Example2>::uninit();
+    unsafe {
+        write(addr_of_mut!((*partial.as_mut_ptr()).a), 1);
+        let expected = [vec![1], vec![0xfe; 23]].concat();
+        assert_eq!(Example2::safe_bytes_partial(&mut partial, 1), &expected[..]);
+
+        write(addr_of_mut!((*partial.as_mut_ptr()).b), 2);
+        let expected = [
+            vec![1],
+            vec![0xfe; 7],
+            2u64.to_ne_bytes().to_vec(),
+            vec![0xfe; 8],
+        ]
+        .concat();
+        assert_eq!(Example2::safe_bytes_partial(&mut partial, 2), &expected[..]);
+
+        write(addr_of_mut!((*partial.as_mut_ptr()).c), 3);
+        let expected = Example2::new(1, 2, 3).expected();
+        assert_eq!(Example2::safe_bytes_partial(&mut partial, 3), &expected[..]);
+
+        assert_eq!(Example2::safe_bytes_partial(&mut partial, 0), &[0xfe; 24]);
+    }
+}
+
+#[test]
+#[should_panic]
+fn partial_bytes_out_of_fields() {
+    let mut partial = MaybeUninit::<Example2>::uninit();
+    unsafe {
+        Example2::safe_bytes_partial(&mut partial, 4);
+    }
+}
+
+#[test]
+fn array_fields() {
+    let mut empty: [u32; 0] = [];
+    assert_eq!(empty.get_fields(), None);
+    assert_eq!(<[u32; 0]>::fields(), None);
+    assert!(empty.safe_bytes().is_empty());
+
+    let mut padded = [Example2::new(1, 2, 3), Example2::new(4, 5, 6)];
+    assert_eq!(padded.get_fields(), <[Example2; 2]>::fields());
+    assert_eq!(<[Example2; 2]>::fields(), Some(Example2::fields()));
+
+    let expected = [
+        Example2::new(1, 2, 3).expected(),
+        Example2::new(4, 5, 6).expected(),
+    ]
+    .concat();
+    assert_eq!(padded.safe_bytes(), &expected[..]);
+
+    let mut empty_padded: [Example2; 0] = [];
+    assert_eq!(<[Example2; 0]>::fields(), None);
+    assert!(empty_padded.safe_bytes().is_empty());
+}
@@ -0,0 +1,44 @@
+use safe_bytes::{Field, PaddingBane, SafeBytes};
+
+/// Builds expected bytes from fields layout and their bytes.
+fn expected(size: usize, fields: &[(Field, &[u8])]) -> Vec<u8> {
+    let mut bytes = vec![0xfe; size];
+    for (field, data) in fields {
+        assert_eq!(field.size(), data.len());
+        bytes[field.offset()..field.end()].copy_from_slice(data);
+    }
+    bytes
+}
+
+#[test]
+fn tuple_with_array() {
+    let mut tuple = ([1u8, 2, 3], 4u64);
+    let (a, b) = tuple.get_fields();
+    let expected = expected(16, &[(a.raw(), &[1, 2, 3]), (b.raw(), &4u64.to_ne_bytes())]);
+    assert_eq!(tuple.safe_bytes(), &expected[..]);
+}
+
+#[test]
+fn nested_tuple() {
+    let mut tuple = (1u8, (2u16, 3u8));
+    let (a, b) = tuple.get_fields();
+    let (ba, bb) = b.sub();
+    let ba = Field::new(b.raw().offset() + ba.raw().offset(), ba.raw().size());
+    let bb = Field::new(b.raw().offset() + bb.raw().offset(), bb.raw().size());
+    let expected = expected(6, &[(a.raw(), &[1]), (ba, &2u16.to_ne_bytes()), (bb, &[3])]);
+    assert_eq!(tuple.safe_bytes(), &expected[..]);
+}
+
+#[test]
+fn tuple_padding_map() {
+    use safe_bytes::PaddingBane;
+
+    let map = <(u8, u64)>::PADDING_MAP;
+    let fields = (1u8, 2u64).get_fields();
+    let (first, second) = if fields.0.raw().offset() == 0 {
+        (fields.0.raw(), fields.1.raw())
+    } else {
+        (fields.1.raw(), fields.0.raw())
+    };
+    assert_eq!(map, &[(first.end(), second.offset() - first.end())]);
+}
@@ -0,0 +1,18 @@
+// Expected compiler output lists trait implementations available in the crate,
+// which depend on enabled features. Compare it only with the default feature set.
+#![cfg(not(any(
+    feature = "alloc",
+    feature = "bitfield",
+    feature = "heapless",
+    feature = "serde",
+    feature = "simd",
+    feature = "std",
+    feature = "unchecked_indexing",
+)))]
+
+#[test]
+fn ui() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/ui/*.rs");
+    t.pass("tests/ui/pass/*.rs");
+}
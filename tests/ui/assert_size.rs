@@ -0,0 +1,12 @@
+use safe_bytes::SafeBytes;
+
+#[derive(SafeBytes)]
+#[safe_bytes(assert_size = 16)]
+#[repr(C)]
+struct Header {
+    kind: u8,
+    len: u64,
+    flags: u16,
+}
+
+fn main() {}
@@ -0,0 +1,34 @@
+use {core::num::Wrapping, safe_bytes::SafeBytes};
+
+type Length = u32;
+
+#[derive(SafeBytes)]
+#[repr(C)]
+struct Inner {
+    value: u16,
+}
+
+#[derive(SafeBytes)]
+#[safe_bytes(byteorder)]
+#[repr(C)]
+struct Nested {
+    flag: u8,
+    inner: Inner,
+}
+
+#[derive(SafeBytes)]
+#[safe_bytes(byteorder)]
+#[repr(C)]
+struct Aliased {
+    tag: [u8; 4],
+    len: Length,
+}
+
+#[derive(SafeBytes)]
+#[safe_bytes(byteorder)]
+#[repr(C)]
+struct Wrapped {
+    counts: [Wrapping<u32>; 2],
+}
+
+fn main() {}
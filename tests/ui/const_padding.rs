@@ -0,0 +1,13 @@
+use safe_bytes::{safe_bytes_const, SafeBytes};
+
+#[derive(SafeBytes)]
+#[repr(C)]
+struct Padded {
+    a: u8,
+    b: u32,
+}
+
+fn main() {
+    let mut padded = Padded { a: 1, b: 2 };
+    safe_bytes_const(&mut padded);
+}
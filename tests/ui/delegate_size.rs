@@ -0,0 +1,12 @@
+struct Tagged {
+    value: u32,
+    _tag: u8,
+}
+
+safe_bytes::delegate_padding_bane!(Tagged { value: u32 });
+
+fn main() {
+    use safe_bytes::SafeBytes;
+
+    Tagged { value: 1, _tag: 2 }.safe_bytes();
+}
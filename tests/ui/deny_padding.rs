@@ -0,0 +1,19 @@
+use safe_bytes::SafeBytes;
+
+#[derive(SafeBytes)]
+#[repr(C)]
+struct Padded {
+    a: u8,
+    b: u32,
+}
+
+#[derive(SafeBytes)]
+#[repr(C)]
+struct Outer {
+    #[safe_bytes(deny_padding)]
+    dense: [u32; 2],
+    #[safe_bytes(deny_padding)]
+    padded: Padded,
+}
+
+fn main() {}
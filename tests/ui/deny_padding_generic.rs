@@ -0,0 +1,13 @@
+use safe_bytes::SafeBytes;
+
+#[derive(SafeBytes)]
+#[repr(C)]
+struct Generic<T> {
+    #[safe_bytes(deny_padding)]
+    value: T,
+}
+
+fn main() {
+    let _ = Generic { value: [1u32; 2] }.safe_bytes();
+    let _ = Generic { value: (1u8, 2u16) }.safe_bytes();
+}
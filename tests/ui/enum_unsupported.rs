@@ -0,0 +1,22 @@
+use safe_bytes::SafeBytes;
+
+#[derive(SafeBytes)]
+#[repr(u8)]
+enum WithFields {
+    Empty,
+    Value(u32),
+}
+
+#[derive(SafeBytes)]
+enum NoRepr {
+    A,
+    B,
+}
+
+#[derive(SafeBytes)]
+#[repr(u8, align(4))]
+enum Aligned {
+    A = 1,
+}
+
+fn main() {}
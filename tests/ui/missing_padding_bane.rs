@@ -0,0 +1,21 @@
+use safe_bytes::SafeBytes;
+
+#[derive(Clone, Copy)]
+struct NotPadding(u8);
+
+#[derive(SafeBytes)]
+#[repr(C)]
+struct Example {
+    a: u8,
+    b: NotPadding,
+}
+
+#[derive(SafeBytes)]
+#[safe_bytes(union_all_init)]
+#[repr(C)]
+union Member {
+    a: u8,
+    b: NotPadding,
+}
+
+fn main() {}
@@ -0,0 +1,13 @@
+use {core::num::Wrapping, safe_bytes::SafeBytes};
+
+#[derive(SafeBytes)]
+#[safe_bytes(no_platform_ints)]
+#[repr(C)]
+struct Example {
+    a: u32,
+    len: usize,
+    offsets: [core::primitive::isize; 2],
+    wrapped: (u8, Wrapping<usize>),
+}
+
+fn main() {}
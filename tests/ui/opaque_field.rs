@@ -0,0 +1,11 @@
+use safe_bytes::SafeBytes;
+
+#[derive(SafeBytes)]
+#[repr(C)]
+struct Example {
+    a: u32,
+    #[safe_bytes(opaque)]
+    flag: bool,
+}
+
+fn main() {}
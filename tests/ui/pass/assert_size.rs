@@ -0,0 +1,29 @@
+use safe_bytes::SafeBytes;
+
+#[derive(SafeBytes)]
+#[safe_bytes(assert_size = 24)]
+#[repr(C)]
+struct Header {
+    kind: u8,
+    len: u64,
+    flags: u16,
+}
+
+#[derive(SafeBytes)]
+#[safe_bytes(assert_size = 8)]
+#[repr(C)]
+struct Generic<T> {
+    value: T,
+}
+
+fn main() {
+    let mut header = Header {
+        kind: 1,
+        len: 2,
+        flags: 3,
+    };
+    header.safe_bytes();
+
+    let mut generic = Generic { value: 1u64 };
+    generic.safe_bytes();
+}
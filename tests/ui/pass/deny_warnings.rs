@@ -0,0 +1,119 @@
+#![deny(warnings)]
+
+use safe_bytes::SafeBytes;
+
+#[derive(SafeBytes)]
+#[repr(C)]
+pub struct Empty {}
+
+#[derive(SafeBytes)]
+#[repr(C)]
+pub struct Unit;
+
+#[derive(SafeBytes)]
+#[safe_bytes(
+    pad_per_field,
+    declaration_order,
+    portable,
+    no_platform_ints,
+    byteorder,
+    layout_const
+)]
+#[repr(C)]
+pub struct EmptyModes {}
+
+#[derive(SafeBytes)]
+#[repr(C)]
+struct Plain {
+    a: u8,
+    b: u64,
+}
+
+#[derive(SafeBytes)]
+#[safe_bytes(no_inline, pad_per_field, declaration_order, portable)]
+#[repr(C)]
+struct AllModes {
+    a: u8,
+    b: [u16; 3],
+    c: u32,
+}
+
+#[derive(SafeBytes)]
+#[safe_bytes(assert_size = 16, unroll = 0, no_tail_pad)]
+#[repr(C)]
+struct Tuple(u8, [Plain; 0], u64);
+
+fn fill(bytes: &mut [u8]) {
+    bytes.fill(0);
+}
+
+#[derive(SafeBytes)]
+#[safe_bytes(no_tail_pad, pad_with_fn = "fill", byteorder)]
+#[repr(C)]
+struct Generic<T> {
+    a: u8,
+    value: T,
+}
+
+#[derive(Clone, Copy, SafeBytes)]
+#[safe_bytes(union_all_init)]
+#[repr(C)]
+union Bits {
+    bytes: [u8; 4],
+    value: u32,
+}
+
+/// Implements `PaddingBane` but not `StaticFields`.
+pub struct Dynamic(u32);
+
+unsafe impl safe_bytes::PaddingBane for Dynamic {
+    type Fields = u32;
+
+    fn get_fields(&self) -> u32 {
+        self.0
+    }
+
+    unsafe fn init_padding(_fields: u32, _bytes: &mut [core::mem::MaybeUninit<u8>]) {}
+}
+
+#[derive(SafeBytes)]
+#[repr(C)]
+struct WithDynamic {
+    a: u8,
+    dynamic: Dynamic,
+}
+
+fn main() {
+    let mut plain = Plain { a: 1, b: 2 };
+    plain.safe_bytes();
+
+    let mut all = AllModes {
+        a: 1,
+        b: [2; 3],
+        c: 3,
+    };
+    all.safe_bytes();
+
+    let mut tuple = Tuple(1, [], 2);
+    tuple.safe_bytes();
+    tuple.safe_bytes_no_tail();
+
+    let mut generic = Generic { a: 1, value: 2u8 };
+    generic.safe_bytes();
+    generic.safe_bytes_no_tail();
+    generic.to_le_safe_bytes(&mut [0; 4]);
+    generic.to_be_safe_bytes(&mut [0; 4]);
+
+    let mut bits = Bits { value: 1 };
+    bits.safe_bytes();
+
+    let mut with_dynamic = WithDynamic {
+        a: 1,
+        dynamic: Dynamic(2),
+    };
+    with_dynamic.safe_bytes();
+
+    Empty {}.safe_bytes();
+    Unit.safe_bytes();
+    EmptyModes {}.safe_bytes();
+}
@@ -0,0 +1,11 @@
+use safe_bytes::SafeBytes;
+
+#[derive(SafeBytes)]
+#[safe_bytes(portable)]
+#[repr(C)]
+struct Node {
+    value: u32,
+    next: *const Node,
+}
+
+fn main() {}
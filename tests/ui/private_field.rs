@@ -0,0 +1,11 @@
+mod external {
+    #[repr(C)]
+    pub struct Header {
+        pub kind: u8,
+        len: u32,
+    }
+}
+
+safe_bytes::impl_padding_bane_struct!(external::Header { kind: u8, len: u32 });
+
+fn main() {}
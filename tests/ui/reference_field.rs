@@ -0,0 +1,11 @@
+use safe_bytes::SafeBytes;
+
+#[derive(SafeBytes)]
+#[repr(C)]
+struct Example<'a> {
+    a: u8,
+    b: &'a u32,
+    c: &'static mut [u8; 4],
+}
+
+fn main() {}
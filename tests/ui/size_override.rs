@@ -0,0 +1,13 @@
+use safe_bytes::SafeBytes;
+
+// Field sizes come from field types only,
+// so byte ranges of fields can't overlap the next field.
+#[derive(SafeBytes)]
+#[repr(C)]
+struct Example {
+    #[safe_bytes(size = 8)]
+    a: u32,
+    b: u32,
+}
+
+fn main() {}
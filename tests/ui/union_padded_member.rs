@@ -0,0 +1,18 @@
+use safe_bytes::SafeBytes;
+
+#[derive(Clone, Copy, SafeBytes)]
+#[repr(C)]
+struct Padded {
+    a: u8,
+    b: u32,
+}
+
+#[derive(SafeBytes)]
+#[safe_bytes(union_all_init)]
+#[repr(C)]
+union PaddedMember {
+    padded: Padded,
+    value: u64,
+}
+
+fn main() {}
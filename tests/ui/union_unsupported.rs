@@ -0,0 +1,27 @@
+use safe_bytes::SafeBytes;
+
+#[derive(SafeBytes)]
+#[repr(C)]
+union NotAllInit {
+    a: u8,
+    b: u32,
+}
+
+#[derive(SafeBytes)]
+#[safe_bytes(union_all_init, portable)]
+#[repr(C)]
+union Portable {
+    a: u32,
+    b: f32,
+}
+
+#[derive(SafeBytes)]
+#[safe_bytes(union_all_init)]
+#[repr(C)]
+union OpaqueField {
+    a: u32,
+    #[safe_bytes(opaque)]
+    b: f32,
+}
+
+fn main() {}
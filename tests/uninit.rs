@@ -0,0 +1,88 @@
+mod common;
+
+use {
+    common::Example2,
+    core::mem::MaybeUninit,
+    safe_bytes::{uninit_bytes, PaddingBane, SafeBytes},
+};
+
+#[derive(SafeBytes)]
+#[repr(C)]
+struct Scratch {
+    len: u32,
+    buffer: MaybeUninit<[u8; 16]>,
+}
+
+#[test]
+fn uninit_buffer() {
+    let mut scratch = Scratch {
+        len: 3,
+        buffer: MaybeUninit::uninit(),
+    };
+    unsafe {
+        (scratch.buffer.as_mut_ptr() as *mut u8).write_bytes(1, 3);
+    }
+
+    let expected = [3u32.to_ne_bytes().to_vec(), vec![0xfe; 16]].concat();
+    assert_eq!(scratch.safe_bytes(), &expected[..]);
+    assert_eq!(Scratch::PADDING_MAP, &[]);
+    assert_eq!(<MaybeUninit<[u8; 16]>>::PADDING_MAP, &[(0, 16)]);
+    assert_eq!(<MaybeUninit<()>>::PADDING_MAP, &[]);
+}
+
+#[test]
+fn manual_padding_loop() {
+    let mut values = [Example2::new(1, 2, 3), Example2::new(4, 5, 6)];
+    let mut buffer = Vec::new();
+    for value in &mut values {
+        let fields = value.get_fields();
+        let count = unsafe {
+            let bytes = uninit_bytes(value);
+            let count = Example2::init_padding_counted(fields, bytes);
+            buffer.extend(bytes.iter().map(|byte| byte.assume_init()));
+            count
+        };
+        assert_eq!(count, 13);
+    }
+
+    let expected = [
+        Example2::new(1, 2, 3).expected(),
+        Example2::new(4, 5, 6).expected(),
+    ]
+    .concat();
+    assert_eq!(buffer, expected);
+}
+
+#[derive(SafeBytes)]
+#[repr(C)]
+struct Words {
+    tag: u8,
+    words: [MaybeUninit<u32>; 8],
+    len: u16,
+}
+
+#[test]
+fn uninit_array() {
+    let mut words = Words {
+        tag: 1,
+        words: [MaybeUninit::uninit(); 8],
+        len: 2,
+    };
+    words.words[0] = MaybeUninit::new(3);
+
+    // Initialized elements are filled too, as they can't be told apart.
+    let expected = [
+        vec![1],
+        vec![0xfe; 3],
+        vec![0xfe; 32],
+        2u16.to_ne_bytes().to_vec(),
+        vec![0xfe; 2],
+    ]
+    .concat();
+    assert_eq!(words.safe_bytes(), &expected[..]);
+
+    let mut scratch = [MaybeUninit::<u32>::uninit(); 8];
+    assert_eq!(scratch.safe_bytes(), &[0xfe; 32]);
+    assert_eq!(scratch[..2].safe_bytes(), &[0xfe; 8]);
+    assert_eq!(<[MaybeUninit<u32>; 8]>::PADDING_MAP, &[]);
+}
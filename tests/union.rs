@@ -0,0 +1,49 @@
+use safe_bytes::SafeBytes;
+
+#[derive(Clone, Copy, SafeBytes)]
+#[safe_bytes(union_all_init)]
+#[repr(C)]
+union Bits {
+    bytes: [u8; 8],
+    value: u64,
+}
+
+#[test]
+fn union_all_init() {
+    let mut bits = Bits {
+        value: 0x0102_0304_0506_0708,
+    };
+    assert_eq!(bits.safe_bytes(), &0x0102_0304_0506_0708u64.to_ne_bytes());
+
+    let mut bits = Bits { bytes: [0xab; 8] };
+    assert_eq!(bits.safe_bytes(), &[0xab; 8]);
+}
+
+#[derive(SafeBytes)]
+#[repr(C)]
+struct Tagged {
+    tag: u8,
+    bits: Bits,
+    flags: u16,
+}
+
+#[test]
+fn union_field() {
+    use safe_bytes::PaddingBane;
+
+    let mut tagged = Tagged {
+        tag: 1,
+        bits: Bits { bytes: [0xab; 8] },
+        flags: 2,
+    };
+    let expected = [
+        vec![1],
+        vec![0xfe; 7],
+        vec![0xab; 8],
+        2u16.to_ne_bytes().to_vec(),
+        vec![0xfe; 6],
+    ]
+    .concat();
+    assert_eq!(tagged.safe_bytes(), &expected[..]);
+    assert_eq!(Tagged::PADDING_MAP, &[(1, 7), (18, 6)]);
+}
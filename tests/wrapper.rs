@@ -0,0 +1,173 @@
+mod common;
+
+use {
+    common::Example2,
+    core::{
+        cmp::Reverse,
+        fmt::Debug,
+        mem::ManuallyDrop,
+        num::Wrapping,
+        sync::atomic::{AtomicUsize, Ordering},
+    },
+    safe_bytes::{PaddingBane, SafeBytes},
+};
+
+#[cfg(safe_bytes_saturating)]
+use core::num::Saturating;
+
+#[cfg(safe_bytes_saturating)]
+#[derive(SafeBytes)]
+#[repr(C)]
+struct Wrappers {
+    a: Reverse<Wrapping<u32>>,
+    b: Saturating<u16>,
+    c: Wrapping<Saturating<Reverse<u64>>>,
+}
+
+#[test]
+#[cfg(safe_bytes_saturating)]
+fn nested_wrappers() {
+    let mut wrappers = Wrappers {
+        a: Reverse(Wrapping(1)),
+        b: Saturating(2),
+        c: Wrapping(Saturating(Reverse(3))),
+    };
+    let expected = [
+        1u32.to_ne_bytes().to_vec(),
+        2u16.to_ne_bytes().to_vec(),
+        vec![0xfe; 2],
+        3u64.to_ne_bytes().to_vec(),
+    ]
+    .concat();
+    assert_eq!(wrappers.safe_bytes(), &expected[..]);
+}
+
+#[test]
+fn restricted_inner_types() {
+    assert_eq!(ManuallyDrop::new(true).safe_bytes(), &[1]);
+    assert_eq!(ManuallyDrop::new(false).safe_bytes(), &[0]);
+    assert_eq!(Wrapping(true).safe_bytes(), &[1]);
+    assert_eq!(Wrapping(7u8).safe_bytes(), &[7]);
+    assert_eq!(Reverse(false).safe_bytes(), &[0]);
+}
+
+#[test]
+fn wrapper_arrays() {
+    let mut wrapping = [Wrapping(1u32), Wrapping(2), Wrapping(3), Wrapping(4)];
+    let expected = (1..=4u32).flat_map(u32::to_ne_bytes).collect::<Vec<_>>();
+    assert_eq!(wrapping.safe_bytes(), &expected[..]);
+
+    let mut manually_drop = [
+        ManuallyDrop::new(Example2::new(1, 2, 3)),
+        ManuallyDrop::new(Example2::new(4, 5, 6)),
+    ];
+    let expected = [
+        Example2::new(1, 2, 3).expected(),
+        Example2::new(4, 5, 6).expected(),
+    ]
+    .concat();
+    assert_eq!(manually_drop.safe_bytes(), &expected[..]);
+
+    let mut reverse = [Reverse(7u16); 8];
+    let expected = [7u16.to_ne_bytes(); 8].concat();
+    assert_eq!(reverse.safe_bytes(), &expected[..]);
+}
+
+#[test]
+fn wrapping_integer_bytes() {
+    let expected: &[u8] = if cfg!(target_endian = "little") {
+        &[0x34, 0x12]
+    } else {
+        &[0x12, 0x34]
+    };
+    assert_eq!(Wrapping(0x1234u16).safe_bytes(), expected);
+    assert_eq!(Wrapping(0x1234u16).safe_bytes(), 0x1234u16.safe_bytes());
+}
+
+/// Checks that array of wrappers has fields and bytes of the array of inner values.
+fn assert_wrapper_array<W, T, const N: usize>(mut wrapped: [W; N], mut plain: [T; N])
+where
+    W: PaddingBane<Fields = T::Fields>,
+    T: PaddingBane,
+    T::Fields: PartialEq + Debug,
+{
+    assert_eq!(wrapped.get_fields(), plain.get_fields());
+    assert_eq!(wrapped.safe_bytes(), plain.safe_bytes());
+}
+
+#[test]
+fn wrapper_array_bytes() {
+    assert_wrapper_array(
+        [Reverse(1u64), Reverse(2), Reverse(3), Reverse(0x0102_0304)],
+        [1u64, 2, 3, 0x0102_0304],
+    );
+    assert_wrapper_array(
+        [Wrapping(1u16), Wrapping(2), Wrapping(0x0102)],
+        [1u16, 2, 0x0102],
+    );
+    #[cfg(safe_bytes_saturating)]
+    assert_wrapper_array(
+        [Saturating(1u32), Saturating(2), Saturating(0x0102_0304)],
+        [1u32, 2, 0x0102_0304],
+    );
+    assert_wrapper_array(
+        [
+            Reverse(Example2::new(1, 2, 3)),
+            Reverse(Example2::new(4, 5, 6)),
+        ],
+        [Example2::new(1, 2, 3), Example2::new(4, 5, 6)],
+    );
+
+    let last: &[u8] = if cfg!(target_endian = "little") {
+        &[4, 3, 2, 1, 0, 0, 0, 0]
+    } else {
+        &[0, 0, 0, 0, 1, 2, 3, 4]
+    };
+    assert_eq!([Reverse(0x0102_0304u64)].safe_bytes(), last);
+}
+
+static DROPS: AtomicUsize = AtomicUsize::new(0);
+
+#[derive(SafeBytes)]
+#[repr(C)]
+struct Tracked {
+    tag: u8,
+    value: u32,
+}
+
+impl Drop for Tracked {
+    fn drop(&mut self) {
+        DROPS.fetch_add(1, Ordering::Relaxed);
+    }
+}
+
+#[test]
+fn manually_drop_array() {
+    let mut examples = ManuallyDrop::new([
+        Example2::new(1, 2, 3),
+        Example2::new(4, 5, 6),
+        Example2::new(7, 8, 9),
+    ]);
+    let expected = [
+        Example2::new(1, 2, 3).expected(),
+        Example2::new(4, 5, 6).expected(),
+        Example2::new(7, 8, 9).expected(),
+    ]
+    .concat();
+    assert_eq!(examples.safe_bytes(), &expected[..]);
+
+    let mut tracked =
+        ManuallyDrop::new([Tracked { tag: 1, value: 2 }, Tracked { tag: 3, value: 4 }]);
+    let expected = [
+        vec![1, 0xfe, 0xfe, 0xfe],
+        2u32.to_ne_bytes().to_vec(),
+        vec![3, 0xfe, 0xfe, 0xfe],
+        4u32.to_ne_bytes().to_vec(),
+    ]
+    .concat();
+    assert_eq!(tracked.safe_bytes(), &expected[..]);
+    assert_eq!(DROPS.load(Ordering::Relaxed), 0);
+
+    unsafe { ManuallyDrop::drop(&mut tracked) };
+    assert_eq!(DROPS.load(Ordering::Relaxed), 2);
+}